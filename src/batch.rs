@@ -0,0 +1,99 @@
+use crate::XY;
+use crate::scalar::Scalar;
+use crate::triangulator::{self, TriangulationError};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// How many polygons a thread takes at a time, enough that taking them costs nothing next to triangulating them but
+// few enough that the threads all finish close together when some polygons are far bigger than others
+const CHUNK_SIZE: usize = 64;
+
+type Triangulated = Result<Vec<[u32; 3]>, TriangulationError>;
+
+pub fn calc_batch<T: Scalar + Send + Sync>(polygons: &[Vec<XY<T>>]) -> Vec<Vec<[u32; 3]>>
+{
+    calc_batch_with_threads(polygons, available_threads())
+}
+
+// Each polygon is triangulated as by calc_indexed, with nothing checked on the way in, which for polygons known to
+// be simple skips most of the work
+pub fn calc_batch_with_threads<T: Scalar + Send + Sync>(polygons: &[Vec<XY<T>>], thread_count: usize) -> Vec<Vec<[u32; 3]>>
+{
+    run(polygons, thread_count, |polygon| triangulator::calc_indexed(polygon))
+}
+
+pub fn try_calc_batch<T: Scalar + Send + Sync>(polygons: &[Vec<XY<T>>]) -> Vec<Triangulated>
+{
+    try_calc_batch_with_threads(polygons, available_threads())
+}
+
+pub fn try_calc_batch_with_threads<T: Scalar + Send + Sync>(polygons: &[Vec<XY<T>>], thread_count: usize) -> Vec<Triangulated>
+{
+    run(polygons, thread_count, |polygon| triangulator::try_calc_indexed(polygon))
+}
+
+fn available_threads() -> usize
+{
+    thread::available_parallelism().map_or(1, |count| count.get())
+}
+
+// Each polygon is triangulated on its own and the results come back in the same order as the polygons did. The
+// threads only last as long as the call.
+fn run<T: Scalar + Send + Sync, R: Send>(polygons: &[Vec<XY<T>>], thread_count: usize, calc: impl Fn(&[XY<T>]) -> R + Sync) -> Vec<R>
+{
+    let chunk_count = polygons.len().div_ceil(CHUNK_SIZE);
+    let thread_count = thread_count.clamp(1, chunk_count.max(1));
+    if thread_count == 1
+        { return polygons.iter().map(|polygon| calc(polygon)).collect(); }
+
+    let next_chunk = AtomicUsize::new(0);
+    let mut chunks: Vec<(usize /*Chunk Index*/, Vec<R>)> = thread::scope(|scope|
+    {
+        let workers: Vec<_> = (0..thread_count).map(|_| scope.spawn(||
+        {
+            let mut done = vec!();
+            loop
+            {
+                let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                if chunk >= chunk_count
+                    { return done; }
+                let polygons = &polygons[chunk * CHUNK_SIZE..((chunk + 1) * CHUNK_SIZE).min(polygons.len())];
+                done.push((chunk, polygons.iter().map(|polygon| calc(polygon)).collect()));
+            }
+        })).collect();
+
+        workers.into_iter().flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))).collect()
+    });
+
+    chunks.sort_unstable_by_key(|(chunk, _)| *chunk);
+    chunks.into_iter().flat_map(|(_, results)| results).collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn batches_match_one_at_a_time()
+    {
+        // Squares of every size with a bow tie every so often, more than fit in one chunk
+        let polygons: Vec<Vec<XY>> = (0..300).map(|i|
+        {
+            let size = 1.0 + i as f32;
+            if i % 7 == 0 { vec!(XY::new(0.0, 0.0), XY::new(size, size), XY::new(size, 0.0), XY::new(0.0, size)) }
+            else { vec!(XY::new(0.0, 0.0), XY::new(size, 0.0), XY::new(size, size), XY::new(0.0, size)) }
+        }).collect();
+
+        let checked: Vec<_> = polygons.iter().map(|polygon| triangulator::try_calc_indexed(polygon)).collect();
+        let unchecked: Vec<_> = polygons.iter().map(|polygon| triangulator::calc_indexed(polygon)).collect();
+        assert!(checked.iter().filter(|result| result.is_err()).count() == 43);
+        for thread_count in [0, 1, 3, 64]
+        {
+            assert!(try_calc_batch_with_threads(&polygons, thread_count) == checked);
+            assert!(calc_batch_with_threads(&polygons, thread_count) == unchecked);
+        }
+        assert!(calc_batch::<f32>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+use graphics_test::XY;
+use graphics_test::triangulator;
+use graphics_test::incremental::{Edit, Triangulation};
+use graphics_test::batch;
+
+use rand::Rng;
+use std::time::Instant;
+
+// Run with `cargo run --release -- --bench`. Times the sweep on coastline-like outlines from a thousand up to a
+// million vertices, the time per vertex should only creep up with log n. Then times dragging single vertices about
+// with incremental, which should stay far below doing the whole thing again, and lots of small outlines one after
+// another against all at once across threads, both checked and not.
+pub fn run()
+{
+    println!("{:>10} {:>12} {:>12} {:>14} {:>12}", "vertices", "triangles", "total ms", "ns per vertex", "ms per edit");
+
+    let mut vertex_count = 1_000;
+    while vertex_count <= 1_000_000
+    {
+        let coastline = make_coastline(vertex_count);
+
+        let start = Instant::now();
+        let triangles = triangulator::calc_indexed(&coastline);
+        let elapsed = start.elapsed();
+
+        let edit_elapsed = time_edits(&coastline, 100);
+        println!("{:>10} {:>12} {:>12.1} {:>14.1} {:>12.3}", vertex_count, triangles.len(), elapsed.as_secs_f64() * 1000.0, elapsed.as_nanos() as f64 / vertex_count as f64,
+            edit_elapsed.as_secs_f64() * 1000.0 / 100.0);
+        vertex_count *= 10;
+    }
+
+    // Checking each outline for crossings costs more than triangulating it, so the batch is timed with and without
+    let tiles: Vec<Vec<XY>> = (0..20_000).map(|_| make_coastline(100)).collect();
+    let start = Instant::now();
+    let one_by_one: Vec<_> = tiles.iter().map(|tile| triangulator::try_calc_indexed(tile)).collect();
+    let sequential = start.elapsed();
+    let start = Instant::now();
+    let batched = batch::try_calc_batch(&tiles);
+    let parallel = start.elapsed();
+    assert!(batched == one_by_one);
+    println!("{} outlines of 100 vertices, checked: {:.1} ms one by one, {:.1} ms batched across {} threads", tiles.len(), sequential.as_secs_f64() * 1000.0,
+        parallel.as_secs_f64() * 1000.0, std::thread::available_parallelism().map_or(1, |count| count.get()));
+
+    let start = Instant::now();
+    let one_by_one: Vec<_> = tiles.iter().map(|tile| triangulator::calc_indexed(tile)).collect();
+    let sequential = start.elapsed();
+    let start = Instant::now();
+    let batched = batch::calc_batch(&tiles);
+    let parallel = start.elapsed();
+    assert!(batched == one_by_one);
+    println!("{} outlines of 100 vertices, unchecked: {:.1} ms one by one, {:.1} ms batched", tiles.len(), sequential.as_secs_f64() * 1000.0, parallel.as_secs_f64() * 1000.0);
+}
+
+// Each edit pulls a random vertex part of the way towards the middle of its neighbours
+fn time_edits(coastline: &[XY], edit_count: usize) -> std::time::Duration
+{
+    let mut rng = rand::rng();
+    let mut triangulation = Triangulation::new(coastline).unwrap();
+
+    let start = Instant::now();
+    for _ in 0..edit_count
+    {
+        let vertex = rng.random_range(0..coastline.len());
+        let points = triangulation.points();
+        let (before, at, after) = (points[(vertex + coastline.len() - 1) % coastline.len()], points[vertex], points[(vertex + 1) % coastline.len()]);
+        let t = rng.random_range(0.0..0.5);
+        let to = XY::new(at.x + ((before.x + after.x) / 2.0 - at.x) * t, at.y + ((before.y + after.y) / 2.0 - at.y) * t);
+
+        // Pulling in a vertex on the inside of a bend can run it into another bay, which gets turned down
+        let _ = triangulation.apply(Edit::Move { vertex: vertex as u32, to });
+    }
+    start.elapsed()
+}
+
+// A star shaped outline whose radius wanders about randomly, so it's full of bays and headlands at every scale
+fn make_coastline(vertex_count: usize) -> Vec<XY>
+{
+    let mut rng = rand::rng();
+    let mut radius = 0.6f32;
+
+    (0..vertex_count).map(|i|
+    {
+        radius = (radius + rng.random_range(-0.05..0.05)).clamp(0.2, 1.0);
+        let angle = i as f32 / vertex_count as f32 * std::f32::consts::TAU;
+        XY::new(radius * angle.cos(), radius * angle.sin())
+    }).collect()
+}
@@ -0,0 +1,128 @@
+use crate::XY;
+use crate::intersections;
+use crate::triangulator::{self, FillRule};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum BooleanOp { Union, Intersection, Difference, Xor }
+
+// Each polygon can be any number of rings, filled by the fill rule. The result is simple outer rings, each with the
+// holes directly inside it, ready for calc_with_holes. Outer rings go counter-clockwise and holes clockwise. Where
+// rings touch at a corner, each one's copy of the point is pulled into its own corner as in calc_filled, so that
+// none of them share a point.
+pub fn calc(subject: &[Vec<XY>], clip: &[Vec<XY>], operation: BooleanOp, fill_rule: FillRule) -> Vec<(Vec<XY>, Vec<Vec<XY>>)>
+{
+    // Once untangled, each polygon winds exactly once round everything inside it and not at all round anything else
+    let untangle = |rings: &[Vec<XY>]|
+    {
+        let rings: Vec<&[XY]> = rings.iter().map(|ring| ring.as_slice()).collect();
+        intersections::untangle_rings(&rings, fill_rule)
+    };
+    let subject = untangle(subject);
+    let mut clip = untangle(clip);
+
+    // Turning the clip inside out leaves only the subject on its own winding round once
+    if operation == BooleanOp::Difference
+    {
+        for ring in &mut clip
+            { ring.reverse(); }
+    }
+
+    let rings: Vec<&[XY]> = subject.iter().chain(clip.iter()).map(|ring| ring.as_slice()).collect();
+    let result = match operation
+    {
+        BooleanOp::Union => intersections::untangle_rings(&rings, FillRule::NonZero),
+        BooleanOp::Intersection => intersections::untangle_rings_by(&rings, |winding_number| winding_number >= 2),
+        BooleanOp::Difference => intersections::untangle_rings(&rings, FillRule::Positive),
+        BooleanOp::Xor => intersections::untangle_rings(&rings, FillRule::EvenOdd),
+    };
+
+    group_holes(result)
+}
+
+// Each hole goes with the smallest outer ring round it
+pub(crate) fn group_holes(rings: Vec<Vec<XY>>) -> Vec<(Vec<XY>, Vec<Vec<XY>>)>
+{
+    let (outers, holes): (Vec<Vec<XY>>, Vec<Vec<XY>>) = triangulator::pull_apart(&rings).into_iter().partition(|ring| triangulator::signed_area(ring) > 0.0);
+    let mut grouped: Vec<(Vec<XY>, Vec<Vec<XY>>)> = outers.into_iter().map(|outer| (outer, vec!())).collect();
+
+    for hole in holes
+    {
+        // Holes only ever touch outer rings at corners, so the middle of an edge is clear of them
+        let probe = XY::new((hole[0].x + hole[1].x) / 2.0, (hole[0].y + hole[1].y) / 2.0);
+        let around = (0..grouped.len())
+            .filter(|&i| intersections::winding_number(&grouped[i].0, probe) != 0)
+            .min_by(|&a, &b| triangulator::signed_area(&grouped[a].0).total_cmp(&triangulator::signed_area(&grouped[b].0)));
+        if let Some(i) = around
+            { grouped[i].1.push(hole); }
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn square(x0: f32, y0: f32, x1: f32, y1: f32) -> Vec<XY>
+    {
+        vec!(XY::new(x0, y0), XY::new(x1, y0), XY::new(x1, y1), XY::new(x0, y1))
+    }
+
+    // Every group has to triangulate as it is, the area is what the triangles cover
+    fn triangulated_area(groups: &[(Vec<XY>, Vec<Vec<XY>>)]) -> f64
+    {
+        groups.iter()
+            .map(|(outer, holes)| triangulator::try_calc_with_holes(outer, holes).unwrap())
+            .map(|triangles| triangles.iter().map(|(a, b, c)| ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) as f64 / 2.0).sum::<f64>())
+            .sum()
+    }
+
+    #[test]
+    fn operations_fill_the_right_area()
+    {
+        let (subject, clip) = (vec!(square(0.0, 0.0, 2.0, 2.0)), vec!(square(1.0, 1.0, 3.0, 3.0)));
+        for (operation, area) in [(BooleanOp::Union, 7.0), (BooleanOp::Intersection, 1.0), (BooleanOp::Difference, 3.0), (BooleanOp::Xor, 6.0)]
+        {
+            let groups = calc(&subject, &clip, operation, FillRule::NonZero);
+            assert!((triangulated_area(&groups) - area).abs() < 1e-3, "{:?} {}", operation, triangulated_area(&groups));
+        }
+
+        // Cutting windows out of a wall leaves them as holes
+        let windows = vec!(square(-0.5, -0.5, 0.0, 0.5), square(0.2, -0.2, 0.6, 0.2));
+        let groups = calc(&[square(-1.0, -1.0, 1.0, 1.0)], &windows, BooleanOp::Difference, FillRule::NonZero);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1.len(), 2);
+        assert!((triangulated_area(&groups) - 3.34).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rings_touching_at_corners_still_triangulate()
+    {
+        // A diamond cut out of a square touching its bottom edge leaves a hole that meets the outer ring at a corner
+        let diamond = vec!(XY::new(2.0, 0.0), XY::new(3.0, 1.0), XY::new(2.0, 2.0), XY::new(1.0, 1.0));
+        let groups = calc(&[square(0.0, 0.0, 4.0, 4.0)], &[diamond], BooleanOp::Difference, FillRule::NonZero);
+        assert!((triangulated_area(&groups) - 14.0).abs() < 1e-3);
+
+        // Two holes touching each other in the middle
+        let left = vec!(XY::new(1.0, 1.0), XY::new(2.0, 2.0), XY::new(1.0, 3.0));
+        let right = vec!(XY::new(3.0, 1.0), XY::new(3.0, 3.0), XY::new(2.0, 2.0));
+        let groups = calc(&[square(0.0, 0.0, 4.0, 4.0)], &[left, right], BooleanOp::Difference, FillRule::NonZero);
+        assert_eq!(groups.len(), 1);
+        assert!((triangulated_area(&groups) - 14.0).abs() < 1e-3);
+
+        // Two squares meeting at a corner stay two pieces
+        let groups = calc(&[square(0.0, 0.0, 1.0, 1.0)], &[square(1.0, 1.0, 2.0, 2.0)], BooleanOp::Union, FillRule::NonZero);
+        assert_eq!(groups.len(), 2);
+        assert!((triangulated_area(&groups) - 2.0).abs() < 1e-3);
+
+        // Overlapping sets of rectangles, which often leave corners touching
+        let subject = vec!(square(0.0, 0.0, 2.0, 2.0), square(2.0, 2.0, 4.0, 3.0), square(1.0, 3.0, 2.0, 4.0));
+        let clip = vec!(square(1.0, 1.0, 2.0, 3.0), square(3.0, 0.0, 4.0, 2.0));
+        for (operation, area) in [(BooleanOp::Union, 10.0), (BooleanOp::Intersection, 1.0), (BooleanOp::Difference, 6.0), (BooleanOp::Xor, 9.0)]
+        {
+            let groups = calc(&subject, &clip, operation, FillRule::NonZero);
+            assert!((triangulated_area(&groups) - area).abs() < 1e-3, "{:?} {}", operation, triangulated_area(&groups));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::XY;
+use crate::scalar::Scalar;
+use crate::triangulator;
+
+use std::collections::HashMap;
+
+pub fn calc_convex<T: Scalar>(points: &[XY<T>]) -> Vec<Vec<u32>>
+{
+    merge_triangles(points, &triangulator::calc_indexed(points))
+}
+
+// Indexed the same way as calc_with_holes_indexed
+pub fn calc_convex_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Vec<Vec<u32>>
+{
+    let points = [vec!(outer.to_vec()), holes.to_vec()].concat().concat();
+    merge_triangles(&points, &triangulator::calc_with_holes_indexed(outer, holes))
+}
+
+// Hertel-Mehlhorn. Every diagonal that leaves both corners it touches convex once it's gone is taken out, which is
+// never more than 4 times as many pieces as the fewest possible. The triangles have to be counter-clockwise, as they
+// come from calc, and so do the pieces.
+pub fn merge_triangles<T: Scalar>(points: &[XY<T>], triangles: &[[u32; 3]]) -> Vec<Vec<u32>>
+{
+    let mut pieces: Vec<Option<Vec<u32>>> = triangles.iter().map(|triangle| Some(triangle.to_vec())).collect();
+
+    let mut owners = HashMap::<(u32, u32) /*Directed Edge*/, usize /*Piece Index*/>::new();
+    for (i, triangle) in triangles.iter().enumerate()
+    {
+        for k in 0..3
+            { owners.insert((triangle[k], triangle[(k + 1) % 3]), i); }
+    }
+
+    // Diagonals are the edges that are in two triangles, one going each way
+    let mut diagonals: Vec<(u32, u32)> = owners.keys()
+        .filter(|(a, b)| a < b && owners.contains_key(&(*b, *a)))
+        .copied()
+        .collect();
+    diagonals.sort();
+
+    let is_convex = |before: u32, at: u32, after: u32| T::orient2d(points[before as usize], points[at as usize], points[after as usize]) >= 0.0;
+
+    for (a, b) in diagonals
+    {
+        let (p, q) = (owners[&(a, b)], owners[&(b, a)]);
+        if p == q
+            { continue; }
+
+        let (first, second) = (pieces[p].as_ref().unwrap(), pieces[q].as_ref().unwrap());
+        let (n, m) = (first.len(), second.len());
+        let i = (0..n).find(|&i| first[i] == a && first[(i + 1) % n] == b).unwrap();
+        let j = (0..m).find(|&j| second[j] == b && second[(j + 1) % m] == a).unwrap();
+
+        // Only the two corners at the ends of the diagonal change
+        if !is_convex(first[(i + n - 1) % n], a, second[(j + 2) % m]) || !is_convex(second[(j + m - 1) % m], b, first[(i + 2) % n])
+            { continue; }
+
+        // Round the first piece from b back to a, then round the second from just after a to just before b
+        let mut merged: Vec<u32> = (1..=n).map(|k| first[(i + k) % n]).collect();
+        merged.extend((2..m).map(|k| second[(j + k) % m]));
+
+        owners.remove(&(a, b));
+        owners.remove(&(b, a));
+        for k in 0..merged.len()
+            { owners.insert((merged[k], merged[(k + 1) % merged.len()]), p); }
+
+        pieces[p] = Some(merged);
+        pieces[q] = None;
+    }
+
+    pieces.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator::signed_area;
+
+    fn ring(points: &[(i32, i32)]) -> Vec<XY<i32>>
+    {
+        points.iter().map(|&(x, y)| XY::new(x, y)).collect()
+    }
+
+    // Every piece turns left or goes straight on at each corner, and together they cover exactly the polygon
+    fn assert_covers(points: &[XY<i32>], pieces: &[Vec<u32>], area: f64)
+    {
+        for piece in pieces
+        {
+            let n = piece.len();
+            assert!((0..n).all(|k| i32::orient2d(points[piece[k] as usize], points[piece[(k + 1) % n] as usize], points[piece[(k + 2) % n] as usize]) >= 0.0),
+                "{:?} isn't convex", piece);
+        }
+        let total: f64 = pieces.iter().map(|piece| signed_area(&piece.iter().map(|&i| points[i as usize]).collect::<Vec<_>>())).sum();
+        assert_eq!(total, area);
+    }
+
+    #[test]
+    fn convex_polygons_stay_whole()
+    {
+        let hexagon = ring(&[(0, 0), (2, -1), (4, 0), (4, 2), (2, 3), (0, 2)]);
+        let pieces = calc_convex(&hexagon);
+        assert_eq!(pieces.len(), 1);
+        assert_covers(&hexagon, &pieces, signed_area(&hexagon));
+    }
+
+    #[test]
+    fn pieces_are_convex_and_cover_the_polygon()
+    {
+        let l_shape = ring(&[(0, 0), (4, 0), (4, 1), (1, 1), (1, 4), (0, 4)]);
+        let pieces = calc_convex(&l_shape);
+        assert_eq!(pieces.len(), 2);
+        assert_covers(&l_shape, &pieces, 7.0);
+
+        // Each cut can only straighten out two of the ten notches, so nothing does it in fewer than 6 pieces
+        let mut comb = vec!((0, 0), (20, 0));
+        comb.extend((0..10).rev().flat_map(|k| [(2 * k + 2, 5), (2 * k + 1, 1)]));
+        comb.push((0, 5));
+        let comb = ring(&comb);
+        let pieces = calc_convex(&comb);
+        assert!(pieces.len() <= 4 * 6, "{} pieces", pieces.len());
+        assert_covers(&comb, &pieces, signed_area(&comb));
+
+        let outer = ring(&[(0, 0), (6, 0), (6, 6), (0, 6)]);
+        let holes = vec!(ring(&[(2, 2), (2, 4), (4, 4), (4, 2)]));
+        let points = [vec!(outer.clone()), holes.clone()].concat().concat();
+        let pieces = calc_convex_with_holes(&outer, &holes);
+        assert!(pieces.len() >= 4 && pieces.len() <= 16, "{} pieces", pieces.len());
+        assert_covers(&points, &pieces, 32.0);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::XY;
+
+use std::f64::consts::TAU;
+
+// Builds up a ring out of lines and curves, each curve cut into just enough straight lines that none of them is
+// further than the tolerance from the curve, ready to go into calc
+#[derive(Debug, Clone)]
+pub struct PathBuilder
+{
+    points: Vec<XY>,
+    tolerance: f32,
+}
+
+impl PathBuilder
+{
+    pub fn new(start: XY, tolerance: f32) -> PathBuilder
+    {
+        PathBuilder { points: vec!(start), tolerance }
+    }
+
+    fn last(&self) -> XY { *self.points.last().unwrap() }
+
+    pub fn line_to(&mut self, to: XY) -> &mut PathBuilder
+    {
+        self.points.push(to);
+        self
+    }
+
+    pub fn quad_to(&mut self, control: XY, to: XY) -> &mut PathBuilder
+    {
+        let from = self.last();
+        self.points.extend(flatten_quad(from, control, to, self.tolerance).into_iter().skip(1));
+        self
+    }
+
+    pub fn cubic_to(&mut self, control_1: XY, control_2: XY, to: XY) -> &mut PathBuilder
+    {
+        let from = self.last();
+        self.points.extend(flatten_cubic(from, control_1, control_2, to, self.tolerance).into_iter().skip(1));
+        self
+    }
+
+    // The same as SVG's arc command, x_rotation is in degrees and sweep goes round the way angles increase, which is
+    // anticlockwise with y going up
+    pub fn arc_to(&mut self, radii: XY, x_rotation: f32, large_arc: bool, sweep: bool, to: XY) -> &mut PathBuilder
+    {
+        let from = self.last();
+        self.points.extend(flatten_arc(from, radii, x_rotation, large_arc, sweep, to, self.tolerance).into_iter().skip(1));
+        self
+    }
+
+    // Coming back round to the start doesn't need the start twice
+    pub fn build(&self) -> Vec<XY>
+    {
+        let mut points = self.points.clone();
+        let (first, last) = (points[0], self.last());
+        if points.len() > 1 && first.x == last.x && first.y == last.y
+            { points.pop(); }
+        points
+    }
+}
+
+fn lerp(a: (f64, f64), b: (f64, f64), t: f64) -> (f64, f64)
+{
+    (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+}
+
+fn to_f64(xy: XY) -> (f64, f64) { (xy.x as f64, xy.y as f64) }
+
+// No curve is ever cut into more steps than this. A tolerance that isn't above zero, NaN included, can't be met and
+// gets this many whatever the curve.
+const MOST_STEPS: usize = 1 << 16;
+
+// How many even steps in t keep a curve within tolerance of its chords, from how far its second derivative can get
+// (Wang's formula). A chord over a step of h is never further from the curve than max |B''| h² / 8.
+fn step_count(max_second_derivative: f64, tolerance: f32) -> usize
+{
+    if tolerance.is_nan() || tolerance <= 0.0
+        { return MOST_STEPS; }
+    let count = (max_second_derivative / (8.0 * tolerance as f64)).sqrt().ceil();
+    if count.is_nan() { 1 } else { (count as usize).clamp(1, MOST_STEPS) }
+}
+
+fn length((x, y): (f64, f64)) -> f64 { x.hypot(y) }
+
+// The ends are always exactly from and to
+pub fn flatten_quad(from: XY, control: XY, to: XY, tolerance: f32) -> Vec<XY>
+{
+    let (p0, p1, p2) = (to_f64(from), to_f64(control), to_f64(to));
+    let second_derivative = 2.0 * length((p0.0 - 2.0 * p1.0 + p2.0, p0.1 - 2.0 * p1.1 + p2.1));
+    let count = step_count(second_derivative, tolerance);
+
+    let mut points = vec!(from);
+    for i in 1..count
+    {
+        let t = i as f64 / count as f64;
+        let (x, y) = lerp(lerp(p0, p1, t), lerp(p1, p2, t), t);
+        points.push(XY::new(x as f32, y as f32));
+    }
+    points.push(to);
+    points
+}
+
+pub fn flatten_cubic(from: XY, control_1: XY, control_2: XY, to: XY, tolerance: f32) -> Vec<XY>
+{
+    let (p0, p1, p2, p3) = (to_f64(from), to_f64(control_1), to_f64(control_2), to_f64(to));
+    let second_difference = length((p0.0 - 2.0 * p1.0 + p2.0, p0.1 - 2.0 * p1.1 + p2.1))
+        .max(length((p1.0 - 2.0 * p2.0 + p3.0, p1.1 - 2.0 * p2.1 + p3.1)));
+    let count = step_count(6.0 * second_difference, tolerance);
+
+    let mut points = vec!(from);
+    for i in 1..count
+    {
+        let t = i as f64 / count as f64;
+        let (a, b, c) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+        let (x, y) = lerp(lerp(a, b, t), lerp(b, c, t), t);
+        points.push(XY::new(x as f32, y as f32));
+    }
+    points.push(to);
+    points
+}
+
+// Works out the centre the way the SVG spec does (its appendix on arc implementation notes), growing the radii if
+// they're too small to reach from one end to the other
+pub fn flatten_arc(from: XY, radii: XY, x_rotation: f32, large_arc: bool, sweep: bool, to: XY, tolerance: f32) -> Vec<XY>
+{
+    let (p1, p2) = (to_f64(from), to_f64(to));
+    let (mut rx, mut ry) = ((radii.x as f64).abs(), (radii.y as f64).abs());
+    if (p1.0 == p2.0 && p1.1 == p2.1) || rx == 0.0 || ry == 0.0
+        { return vec!(from, to); }
+
+    let (sin, cos) = (x_rotation as f64).to_radians().sin_cos();
+    let (dx, dy) = ((p1.0 - p2.0) / 2.0, (p1.1 - p2.1) / 2.0);
+    let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0
+        { rx *= lambda.sqrt(); ry *= lambda.sqrt(); }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+    let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+    let (cx1, cy1) = (coefficient * rx * y1 / ry, -coefficient * ry * x1 / rx);
+    let (cx, cy) = (cos * cx1 - sin * cy1 + (p1.0 + p2.0) / 2.0, sin * cx1 + cos * cy1 + (p1.1 + p2.1) / 2.0);
+
+    let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = (end - start).rem_euclid(TAU);
+    if !sweep && delta > 0.0
+        { delta -= TAU; }
+
+    let points = ellipse_points((cx, cy), (rx, ry), (sin, cos), start, delta, tolerance);
+    [vec!(from), points, vec!(to)].concat()
+}
+
+// A whole ellipse as a counter-clockwise ring
+pub fn flatten_ellipse(centre: XY, radii: XY, x_rotation: f32, tolerance: f32) -> Vec<XY>
+{
+    let (sin, cos) = (x_rotation as f64).to_radians().sin_cos();
+    let radii = ((radii.x as f64).abs(), (radii.y as f64).abs());
+    let mut points = ellipse_points(to_f64(centre), radii, (sin, cos), 0.0, TAU, tolerance);
+    let (x, y) = ellipse_point(to_f64(centre), radii, (sin, cos), 0.0);
+    points.insert(0, XY::new(x as f32, y as f32));
+    points
+}
+
+fn ellipse_point(centre: (f64, f64), (rx, ry): (f64, f64), (sin, cos): (f64, f64), angle: f64) -> (f64, f64)
+{
+    let (x, y) = (rx * angle.cos(), ry * angle.sin());
+    (centre.0 + cos * x - sin * y, centre.1 + sin * x + cos * y)
+}
+
+// The points strictly between the start and end angles. A chord across an angle a of a circle of radius r is at most
+// r (1 - cos(a/2)) from it, the largest radius keeps the whole ellipse within tolerance.
+fn ellipse_points(centre: (f64, f64), radii: (f64, f64), rotation: (f64, f64), start: f64, delta: f64, tolerance: f32) -> Vec<XY>
+{
+    let radius = radii.0.max(radii.1);
+    let step = 2.0 * (1.0 - (tolerance as f64 / radius).min(1.0)).acos();
+    let count = if tolerance > 0.0 && step > 0.0 { ((delta.abs() / step).ceil() as usize).clamp(3, MOST_STEPS) } else { MOST_STEPS };
+
+    (1..count).map(|i|
+    {
+        let (x, y) = ellipse_point(centre, radii, rotation, start + delta * i as f64 / count as f64);
+        XY::new(x as f32, y as f32)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn distance_to_chords(points: &[XY], p: (f64, f64)) -> f64
+    {
+        points.windows(2).map(|pair|
+        {
+            let (a, b) = (to_f64(pair[0]), to_f64(pair[1]));
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0);
+            length((p.0 - a.0 - t * dx, p.1 - a.1 - t * dy))
+        }).fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn curves_stay_within_tolerance()
+    {
+        let (from, control_1, control_2, to) = (XY::new(0.0, 0.0), XY::new(10.0, 30.0), XY::new(40.0, -20.0), XY::new(50.0, 10.0));
+        let points = flatten_cubic(from, control_1, control_2, to, 0.01);
+        assert_eq!((points[0].x, points[0].y), (0.0, 0.0));
+        assert_eq!((points[points.len() - 1].x, points[points.len() - 1].y), (50.0, 10.0));
+        assert!(points.len() > 10 && points.len() < 1000, "{} points", points.len());
+        for i in 0..=1000
+        {
+            let t = i as f64 / 1000.0;
+            let (p0, p1, p2, p3) = (to_f64(from), to_f64(control_1), to_f64(control_2), to_f64(to));
+            let (a, b, c) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+            assert!(distance_to_chords(&points, lerp(lerp(a, b, t), lerp(b, c, t), t)) <= 0.0101);
+        }
+
+        let mut ellipse = flatten_ellipse(XY::new(5.0, 5.0), XY::new(4.0, 2.0), 30.0, 0.01);
+        ellipse.push(ellipse[0]);
+        let (sin, cos) = 30.0_f64.to_radians().sin_cos();
+        for i in 0..1000
+            { assert!(distance_to_chords(&ellipse, ellipse_point((5.0, 5.0), (4.0, 2.0), (sin, cos), TAU * i as f64 / 1000.0)) <= 0.0101); }
+    }
+
+    #[test]
+    fn tolerances_that_cant_be_met_get_the_most_steps()
+    {
+        let (from, control, to) = (XY::new(0.0, 0.0), XY::new(1.0, 2.0), XY::new(2.0, 0.0));
+        for tolerance in [0.0, -0.0, -1.0, f32::NAN, f32::NEG_INFINITY]
+        {
+            assert_eq!(flatten_quad(from, control, to, tolerance).len(), MOST_STEPS + 1);
+            assert_eq!(flatten_cubic(from, control, control, to, tolerance).len(), MOST_STEPS + 1);
+            assert_eq!(flatten_arc(from, XY::new(1.0, 1.0), 0.0, false, true, to, tolerance).len(), MOST_STEPS + 1);
+            assert_eq!(flatten_ellipse(from, XY::new(1.0, 1.0), 0.0, tolerance).len(), MOST_STEPS);
+        }
+
+        // Nothing needs more, however fine the tolerance or big the curve
+        assert_eq!(flatten_quad(from, XY::new(1e30, 1e30), to, 1e-30).len(), MOST_STEPS + 1);
+        assert_eq!(flatten_quad(from, control, to, f32::INFINITY).len(), 2);
+    }
+}
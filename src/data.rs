@@ -1,785 +1,1030 @@
-use rand::Rng;
-
-use crate::triangulator;
-use crate::XY;
-use crate::Vertex;
-
-pub fn make_shapes() -> Vec<Vec<Vertex>>
-{
-    let paths = vec!(
-        vec!(XY::new(-1.0,-1.0), XY::new( 1.0,-1.0), XY::new( 1.0, 1.0), XY::new(-1.0, 1.0)),
-
-        vec!(XY::new(-0.6,-0.2), XY::new(-0.4,-0.2), XY::new(-0.2,-0.2), XY::new( 0.0,-0.2), XY::new( 0.2,-0.2),
-            XY::new( 0.4,-0.2), XY::new( 0.6,-0.2), XY::new( 0.7, 0.2), XY::new( 0.5, 0.2), XY::new( 0.3, 0.2),
-            XY::new( 0.1, 0.2), XY::new(-0.1, 0.2), XY::new(-0.3, 0.2), XY::new(-0.5, 0.2), XY::new(-0.7, 0.2)),
-
-        vec!(XY::new(-0.6, 0.0), XY::new(-0.5, 0.1), XY::new(-0.4, 0.1), XY::new(-0.3, 0.2), XY::new(-0.2, 0.3), 
-            XY::new(-0.1, 0.3), XY::new( 0.0, 0.4), XY::new( 0.1, 0.4), XY::new( 0.2, 0.5), XY::new( 0.3, 0.4), 
-            XY::new( 0.4, 0.2), XY::new( 0.5, 0.2), XY::new( 0.6, 0.1), XY::new( 0.5,-0.3), XY::new( 0.4,-0.3), 
-            XY::new( 0.3,-0.2), XY::new( 0.2,-0.4), XY::new( 0.1,-0.4), XY::new( 0.0,-0.3), XY::new(-0.1,-0.5), 
-            XY::new(-0.2,-0.7), XY::new(-0.3,-0.6), XY::new(-0.4,-0.7), XY::new(-0.5,-0.6)),
-            
-        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), 
-            XY::new(-0.05,-0.8), XY::new( 0.05,-0.8), 
-            XY::new( 0.1, 0.6), XY::new( 0.65, 0.6), 
-            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
-
-        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), 
-            XY::new(-0.05,-0.8), XY::new( 0.0, 0.0), XY::new( 0.05,-0.8), 
-            XY::new( 0.1, 0.6), XY::new( 0.65, 0.6), 
-            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
-
-        vec!(XY::new(-0.5, 0.0), XY::new(-0.2,-0.1), XY::new(-0.1,-0.8), XY::new( 0.1,-0.2), XY::new( 0.0, 0.0)),
-
-        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), XY::new( 0.0,-0.6),
-            XY::new(-0.4,-0.6), XY::new(-0.3,-0.8), XY::new( 0.1,-0.8),
-            XY::new( 0.2,-0.6), XY::new( 0.3, 0.6), XY::new( 0.65, 0.6), 
-            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
-
-        vec!(XY::new(-0.5, 0.5), XY::new(-0.4, 0.6), XY::new( 0.5, 0.0), XY::new(-0.4,-0.6),
-            XY::new(-0.5,-0.5), XY::new( 0.0, 0.0)),
-
-        vec!(XY::new( 0.5, 0.5), XY::new( 0.4, 0.6), XY::new(-0.5, 0.0), XY::new( 0.4,-0.6),
-            XY::new( 0.5,-0.5), XY::new(-0.0, 0.0)),
-
-        vec!(XY::new(-0.5,-0.4), XY::new(-0.4,-0.5), XY::new(-0.2,-0.3), XY::new( 0.2,-0.3), XY::new( 0.4,-0.5), 
-            XY::new( 0.5,-0.4), XY::new( 0.1, 0.0), XY::new( 0.5, 0.4), XY::new( 0.4, 0.5), XY::new( 0.2, 0.3),
-            XY::new(-0.2, 0.3), XY::new(-0.4, 0.5), XY::new(-0.5, 0.4), XY::new(-0.1, 0.0)), 
-
-        vec!(XY::new(-0.5,-0.2), XY::new(-0.5,-0.1), XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.5, 0.2), 
-            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.5,-0.2)),
-
-        vec!(XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.5, 0.2), 
-            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.5,-0.2), 
-            XY::new(-0.5,-0.2), XY::new(-0.5,-0.1)),
-
-        vec!(XY::new(-0.5,-0.2), XY::new(-0.5,-0.1), XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.3, 0.2), 
-            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.3,-0.2)), 
-
-        vec!(XY::new(-0.7,-0.5), XY::new(-0.8,-0.6),
-            XY::new(-0.4,-0.6), XY::new(-0.5,-0.5), XY::new(-0.5, 0.6), XY::new(-0.1, 0.6), 
-            XY::new(-0.1,-0.2), XY::new(-0.2,-0.3), XY::new( 0.2,-0.3), XY::new( 0.1, -0.2), 
-            XY::new( 0.1, 0.6), XY::new( 0.5, 0.6), XY::new( 0.5,-0.5), XY::new( 0.4,-0.6), XY::new(0.8, -0.6),
-            XY::new( 0.7,-0.5), XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
-
-        vec!(XY::new((151.49479508399963-320.0)/320.0, (37.25356125831604-320.0)/320.0),
-            XY::new((143.24405981522432-320.0)/320.0, (42.898801179057045-320.0)/320.0),
-            XY::new((134.99955428620888-320.0)/320.0, (48.53977864627814-320.0)/320.0),
-            XY::new((126.74613561126898-320.0)/320.0, (54.18685458176333-320.0)/320.0),
-            XY::new((118.48821103252703-320.0)/320.0, (59.83701350406045-320.0)/320.0),
-            XY::new((110.24265909722453-320.0)/320.0, (65.47870693347795-320.0)/320.0),
-            XY::new((101.97458558996004-320.0)/320.0, (71.13580985950102-320.0)/320.0),
-            XY::new((93.72768214011012-320.0)/320.0, (76.77842800939834-320.0)/320.0),
-            XY::new((85.47457652172307-320.0)/320.0, (82.42528974829474-320.0)/320.0),
-            XY::new((77.22163051901498-320.0)/320.0, (88.07204227646343-320.0)/320.0),
-            XY::new((68.97166344726429-320.0)/320.0, (93.7167565887139-320.0)/320.0),
-            XY::new((60.72420496481209-320.0)/320.0, (99.35975449776015-320.0)/320.0),
-            XY::new((52.46207843301818-320.0)/320.0, (105.01278844056651-320.0)/320.0),
-            XY::new((44.20561546459794-320.0)/320.0, (110.66194731369615-320.0)/320.0),
-            XY::new((39.237521100687445-320.0)/320.0, (115.93976968318748-320.0)/320.0),
-            XY::new((48.528410255908966-320.0)/320.0, (119.62811261415482-320.0)/320.0),
-            XY::new((57.83031928908349-320.0)/320.0, (123.32083027054068-320.0)/320.0),
-            XY::new((67.11752508791687-320.0)/320.0, (127.00771096454991-320.0)/320.0),
-            XY::new((76.41461966428506-320.0)/320.0, (130.6985173541634-320.0)/320.0),
-            XY::new((85.7016077983335-320.0)/320.0, (134.3853116385344-320.0)/320.0),
-            XY::new((95.00132881860736-320.0)/320.0, (138.07716068678383-320.0)/320.0),
-            XY::new((104.29940029600584-320.0)/320.0, (141.7683548913792-320.0)/320.0),
-            XY::new((113.59307927683949-320.0)/320.0, (145.45780534105688-320.0)/320.0),
-            XY::new((122.88929328344784-320.0)/320.0, (149.14826215775065-320.0)/320.0),
-            XY::new((132.18410838172713-320.0)/320.0, (152.83816362892685-320.0)/320.0),
-            XY::new((132.66015625-320.0)/320.0, (156.833984375-320.0)/320.0),
-            XY::new((123.64010634063743-320.0)/320.0, (161.16539447999094-320.0)/320.0),
-            XY::new((114.63182582109948-320.0)/320.0, (165.4911529472938-320.0)/320.0),
-            XY::new((105.61387369197837-320.0)/320.0, (169.82155570236682-320.0)/320.0),
-            XY::new((96.59221583773251-320.0)/320.0, (174.15373793930667-320.0)/320.0),
-            XY::new((87.59105002467186-320.0)/320.0, (178.47607993864767-320.0)/320.0),
-            XY::new((78.57694321507006-320.0)/320.0, (182.804636178902-320.0)/320.0),
-            XY::new((69.56137649645098-320.0)/320.0, (187.13389346457552-320.0)/320.0),
-            XY::new((60.54597211981309-320.0)/320.0, (191.46307279395114-320.0)/320.0),
-            XY::new((51.53231927464367-320.0)/320.0, (195.79141104138398-320.0)/320.0),
-            XY::new((42.510309571167454-320.0)/320.0, (200.1237622356275-320.0)/320.0),
-            XY::new((38.71576214581728-320.0)/320.0, (203.7802967503667-320.0)/320.0),
-            XY::new((48.536488713885774-320.0)/320.0, (205.69795341750432-320.0)/320.0),
-            XY::new((58.353401970622144-320.0)/320.0, (207.61486547355344-320.0)/320.0),
-            XY::new((68.17180446481689-320.0)/320.0, (209.53206832744945-320.0)/320.0),
-            XY::new((77.98697050444207-320.0)/320.0, (211.44863921092656-320.0)/320.0),
-            XY::new((87.79986481183732-320.0)/320.0, (213.3647665017197-320.0)/320.0),
-            XY::new((97.60523153121176-320.0)/320.0, (215.27942390846147-320.0)/320.0),
-            XY::new((107.43585420178715-320.0)/320.0, (217.19901295064483-320.0)/320.0),
-            XY::new((117.23927618656307-320.0)/320.0, (219.11329061631113-320.0)/320.0),
-            XY::new((119.6603193921037-320.0)/320.0, (226.38347680238076-320.0)/320.0),
-            XY::new((118.40519514408516-320.0)/320.0, (236.29895836172727-320.0)/320.0),
-            XY::new((117.14928548736498-320.0)/320.0, (246.22064464981668-320.0)/320.0),
-            XY::new((115.89331520183623-320.0)/320.0, (256.1428099054938-320.0)/320.0),
-            XY::new((114.63776098084782-320.0)/320.0, (266.06168825130226-320.0)/320.0),
-            XY::new((113.3823506491608-320.0)/320.0, (275.9794298716297-320.0)/320.0),
-            XY::new((112.12614080851199-320.0)/320.0, (285.90348761275527-320.0)/320.0),
-            XY::new((110.87075745640323-320.0)/320.0, (295.8210160944145-320.0)/320.0),
-            XY::new((117.36467342311516-320.0)/320.0, (300.38176663336344-320.0)/320.0),
-            XY::new((127.08043038472533-320.0)/320.0, (302.69124984554946-320.0)/320.0),
-            XY::new((136.82198214903474-320.0)/320.0, (305.0068646091968-320.0)/320.0),
-            XY::new((146.5470655219906-320.0)/320.0, (307.31856475522727-320.0)/320.0),
-            XY::new((156.2753984630108-320.0)/320.0, (309.63103733956814-320.0)/320.0),
-            XY::new((166.0112459915108-320.0)/320.0, (311.94529617830995-320.0)/320.0),
-            XY::new((175.74134051223518-320.0)/320.0, (314.25818749881-320.0)/320.0),
-            XY::new((185.46250711433822-320.0)/320.0, (316.56895660914597-320.0)/320.0),
-            XY::new((195.19153725152137-320.0)/320.0, (318.8815949204436-320.0)/320.0),
-            XY::new((204.9115853912008-320.0)/320.0, (321.19209816676084-320.0)/320.0),
-            XY::new((214.64584860306786-320.0)/320.0, (323.5059804056473-320.0)/320.0),
-            XY::new((224.3770621312433-320.0)/320.0, (325.81913771972177-320.0)/320.0),
-            XY::new((231.08221468760166-320.0)/320.0, (328.6115250462899-320.0)/320.0),
-            XY::new((222.53925752235773-320.0)/320.0, (333.8026999744525-320.0)/320.0),
-            XY::new((213.998013121658-320.0)/320.0, (338.99283413181547-320.0)/320.0),
-            XY::new((205.4527522297576-320.0)/320.0, (344.18540893215686-320.0)/320.0),
-            XY::new((196.90229369455483-320.0)/320.0, (349.3811421090504-320.0)/320.0),
-            XY::new((188.35298773644718-320.0)/320.0, (354.5761749161302-320.0)/320.0),
-            XY::new((179.809910631373-320.0)/320.0, (359.7674227263906-320.0)/320.0),
-            XY::new((171.25848214607686-320.0)/320.0, (364.9637452987954-320.0)/320.0),
-            XY::new((162.72439449385274-320.0)/320.0, (370.14953061856795-320.0)/320.0),
-            XY::new((154.1675122132292-320.0)/320.0, (375.3491672197124-320.0)/320.0),
-            XY::new((145.62358783320633-320.0)/320.0, (380.5409298812574-320.0)/320.0),
-            XY::new((137.0795088929026-320.0)/320.0, (385.7327864622075-320.0)/320.0),
-            XY::new((128.53733253199607-320.0)/320.0, (390.9234869303182-320.0)/320.0),
-            XY::new((128.63895247876644-320.0)/320.0, (400.60194082558155-320.0)/320.0),
-            XY::new((129.3200240572296-320.0)/320.0, (410.57035211036055-320.0)/320.0),
-            XY::new((130.00158578154515-320.0)/320.0, (420.54593734806986-320.0)/320.0),
-            XY::new((130.68386690481566-320.0)/320.0, (430.53205197048374-320.0)/320.0),
-            XY::new((131.36575048890882-320.0)/320.0, (440.5123480649381-320.0)/320.0),
-            XY::new((132.04718094890632-320.0)/320.0, (450.4860120703561-320.0)/320.0),
-            XY::new((132.72882843017578-320.0)/320.0, (460.46285247802734-320.0)/320.0),
-            XY::new((133.4103733994575-320.0)/320.0, (470.43819248296904-320.0)/320.0),
-            XY::new((134.09214802986799-320.0)/320.0, (480.41689389170415-320.0)/320.0),
-            XY::new((134.77365913712083-320.0)/320.0, (490.3917382796776-320.0)/320.0),
-            XY::new((135.45518935550353-320.0)/320.0, (500.3668623850972-320.0)/320.0),
-            XY::new((136.13680260789624-320.0)/320.0, (510.3432018064814-320.0)/320.0),
-            XY::new((136.81853728135684-320.0)/320.0, (520.3213183907683-320.0)/320.0),
-            XY::new((137.50074147951455-320.0)/320.0, (530.3063071092583-320.0)/320.0),
-            XY::new((138.18219891376793-320.0)/320.0, (540.2803659196943-320.0)/320.0),
-            XY::new((138.86291046370752-320.0)/320.0, (550.2435076960828-320.0)/320.0),
-            XY::new((146.7712722722208-320.0)/320.0, (554.1727890158072-320.0)/320.0),
-            XY::new((156.47837973653805-320.0)/320.0, (556.5745475636795-320.0)/320.0),
-            XY::new((166.1754439281267-320.0)/320.0, (558.9738211780932-320.0)/320.0),
-            XY::new((175.88035914469765-320.0)/320.0, (561.375037314152-320.0)/320.0),
-            XY::new((185.5873788335448-320.0)/320.0, (563.7767741443822-320.0)/320.0),
-            XY::new((195.3061499393807-320.0)/320.0, (566.1814185417024-320.0)/320.0),
-            XY::new((205.00491508771142-320.0)/320.0, (568.5811130113925-320.0)/320.0),
-            XY::new((214.70652095051446-320.0)/320.0, (570.9815103382716-320.0)/320.0),
-            XY::new((224.42699172980792-320.0)/320.0, (573.3865752733545-320.0)/320.0),
-            XY::new((234.1214802294271-320.0)/320.0, (575.7852116031572-320.0)/320.0),
-            XY::new((243.8371512884753-320.0)/320.0, (578.1890889785918-320.0)/320.0),
-            XY::new((253.55417044495698-320.0)/320.0, (580.5932999039069-320.0)/320.0),
-            XY::new((263.2457120077579-320.0)/320.0, (582.991207094703-320.0)/320.0),
-            XY::new((272.9612408457324-320.0)/320.0, (585.3950492814183-320.0)/320.0),
-            XY::new((282.66034239530563-320.0)/320.0, (587.7948269844055-320.0)/320.0),
-            XY::new((292.3683239419479-320.0)/320.0, (586.2480004171375-320.0)/320.0),
-            XY::new((302.0482823237362-320.0)/320.0, (583.785052426505-320.0)/320.0),
-            XY::new((311.7466271368321-320.0)/320.0, (581.3174262314569-320.0)/320.0),
-            XY::new((321.4311059716565-320.0)/320.0, (578.8533280663833-320.0)/320.0),
-            XY::new((331.12736044824123-320.0)/320.0, (576.3862337321043-320.0)/320.0),
-            XY::new((340.8185914037663-320.0)/320.0, (573.9204175718228-320.0)/320.0),
-            XY::new((350.5053490380815-320.0)/320.0, (571.4557395938609-320.0)/320.0),
-            XY::new((360.19495760607606-320.0)/320.0, (568.9903362304067-320.0)/320.0),
-            XY::new((369.89738490753734-320.0)/320.0, (566.5216712957154-320.0)/320.0),
-            XY::new((379.58926456619884-320.0)/320.0, (564.0556900807896-320.0)/320.0),
-            XY::new((389.2769663099134-320.0)/320.0, (561.5907718856433-320.0)/320.0),
-            XY::new((398.96596419299067-320.0)/320.0, (559.1255239035586-320.0)/320.0),
-            XY::new((408.65479750931263-320.0)/320.0, (556.6603177934885-320.0)/320.0),
-            XY::new((418.35481561221604-320.0)/320.0, (554.1922658501462-320.0)/320.0),
-            XY::new((428.03891108685275-320.0)/320.0, (551.7282652264221-320.0)/320.0),
-            XY::new((437.7337135553862-320.0)/320.0, (549.2615403379787-320.0)/320.0),
-            XY::new((447.4228872297281-320.0)/320.0, (546.796247627939-320.0)/320.0),
-            XY::new((451.3728691500146-320.0)/320.0, (542.1882207462331-320.0)/320.0),
-            XY::new((445.66900289294426-320.0)/320.0, (533.9781102246925-320.0)/320.0),
-            XY::new((439.9586025842-320.0)/320.0, (525.7585946287727-320.0)/320.0),
-            XY::new((434.256012218073-320.0)/320.0, (517.5503206169233-320.0)/320.0),
-            XY::new((428.551600714447-320.0)/320.0, (509.3394252707949-320.0)/320.0),
-            XY::new((422.84682364948094-320.0)/320.0, (501.12800373788923-320.0)/320.0),
-            XY::new((414.99830865859985-320.0)/320.0, (499.83560848236084-320.0)/320.0),
-            XY::new((405.636616856631-320.0)/320.0, (503.38448511317256-320.0)/320.0),
-            XY::new((396.294921875-320.0)/320.0, (506.92578125-320.0)/320.0),
-            XY::new((386.94598599523306-320.0)/320.0, (510.46982230246067-320.0)/320.0),
-            XY::new((377.5818417440023-320.0)/320.0, (514.0196286199207-320.0)/320.0),
-            XY::new((368.2313457332639-320.0)/320.0, (517.5642610945797-320.0)/320.0),
-            XY::new((358.88610163668636-320.0)/320.0, (521.1069026475307-320.0)/320.0),
-            XY::new((349.5324653972639-320.0)/320.0, (524.6527255356777-320.0)/320.0),
-            XY::new((339.9760579795111-320.0)/320.0, (526.1288545874413-320.0)/320.0),
-            XY::new((330.0828419160389-320.0)/320.0, (524.6017138528114-320.0)/320.0),
-            XY::new((320.20512606039483-320.0)/320.0, (523.076965767712-320.0)/320.0),
-            XY::new((310.32920911049587-320.0)/320.0, (521.5524953660497-320.0)/320.0),
-            XY::new((300.42727717177695-320.0)/320.0, (520.0240092278582-320.0)/320.0),
-            XY::new((290.5495745504995-320.0)/320.0, (518.4992631856476-320.0)/320.0),
-            XY::new((280.6755625357655-320.0)/320.0, (516.9750868343799-320.0)/320.0),
-            XY::new((270.78223131948107-320.0)/320.0, (515.4479283244837-320.0)/320.0),
-            XY::new((260.89576650294475-320.0)/320.0, (513.9218297286425-320.0)/320.0),
-            XY::new((251.0253504235675-320.0)/320.0, (512.3982084546446-320.0)/320.0),
-            XY::new((241.1368497586932-320.0)/320.0, (510.8717956003352-320.0)/320.0),
-            XY::new((231.25896975956857-320.0)/320.0, (509.3470221776515-320.0)/320.0),
-            XY::new((221.38082339591347-320.0)/320.0, (507.82220763829537-320.0)/320.0),
-            XY::new((211.4969937756723-320.0)/320.0, (506.29651581772123-320.0)/320.0),
-            XY::new((201.60670001104518-320.0)/320.0, (504.7698261762016-320.0)/320.0),
-            XY::new((197.65330908447504-320.0)/320.0, (501.7600181866437-320.0)/320.0),
-            XY::new((205.65117263793945-320.0)/320.0, (495.7616205215454-320.0)/320.0),
-            XY::new((213.6440149502596-320.0)/320.0, (489.7669887873053-320.0)/320.0),
-            XY::new((221.65740885480773-320.0)/320.0, (483.7569433588942-320.0)/320.0),
-            XY::new((229.65527453401592-320.0)/320.0, (477.75854409948806-320.0)/320.0),
-            XY::new((237.65914262086153-320.0)/320.0, (471.75564303435385-320.0)/320.0),
-            XY::new((237.0732385488227-320.0)/320.0, (462.65760190505534-320.0)/320.0),
-            XY::new((234.75851440429688-320.0)/320.0, (452.9357604980469-320.0)/320.0),
-            XY::new((232.44086726858222-320.0)/320.0, (443.20164252804534-320.0)/320.0),
-            XY::new((230.12580379005522-320.0)/320.0, (433.4783759182319-320.0)/320.0),
-            XY::new((227.80867317027878-320.0)/320.0, (423.7464273151709-320.0)/320.0),
-            XY::new((225.49342685937881-320.0)/320.0, (414.022392809391-320.0)/320.0),
-            XY::new((223.17743436388264-320.0)/320.0, (404.2952243283071-320.0)/320.0),
-            XY::new((220.8591128132539-320.0)/320.0, (394.5582738156663-320.0)/320.0),
-            XY::new((220.94976359675638-320.0)/320.0, (386.5473018042976-320.0)/320.0),
-            XY::new((230.9078685163695-320.0)/320.0, (385.5120532730507-320.0)/320.0),
-            XY::new((240.853293600725-320.0)/320.0, (384.4781229424989-320.0)/320.0),
-            XY::new((250.80108275601015-320.0)/320.0, (383.44394684219697-320.0)/320.0),
-            XY::new((260.73664950649254-320.0)/320.0, (382.4110413879389-320.0)/320.0),
-            XY::new((270.68349742889404-320.0)/320.0, (381.3769631385803-320.0)/320.0),
-            XY::new((280.6434704780295-320.0)/320.0, (380.3415203958484-320.0)/320.0),
-            XY::new((290.5830474381801-320.0)/320.0, (379.30819803860504-320.0)/320.0),
-            XY::new((300.52618408203125-320.0)/320.0, (378.2745056152344-320.0)/320.0),
-            XY::new((310.4754348144925-320.0)/320.0, (377.2401775687904-320.0)/320.0),
-            XY::new((320.03229942172766-320.0)/320.0, (376.6669561713934-320.0)/320.0),
-            XY::new((320.8056374369189-320.0)/320.0, (386.65004691295326-320.0)/320.0),
-            XY::new((321.5779208282038-320.0)/320.0, (396.6195234186307-320.0)/320.0),
-            XY::new((322.3504435848008-320.0)/320.0, (406.59208991288324-320.0)/320.0),
-            XY::new((323.1216061189334-320.0)/320.0, (416.5470971716859-320.0)/320.0),
-            XY::new((323.8943871036172-320.0)/320.0, (426.52299715578556-320.0)/320.0),
-            XY::new((324.6661626431887-320.0)/320.0, (436.4859177575272-320.0)/320.0),
-            XY::new((325.43917311599944-320.0)/320.0, (446.46478022472-320.0)/320.0),
-            XY::new((334.6937026977539-320.0)/320.0, (447.6532325744629-320.0)/320.0),
-            XY::new((344.7007271235343-320.0)/320.0, (448.092137154541-320.0)/320.0),
-            XY::new((354.67739685299966-320.0)/320.0, (448.52971038828946-320.0)/320.0),
-            XY::new((364.6772343661578-320.0)/320.0, (448.96829975290166-320.0)/320.0),
-            XY::new((374.6735589080199-320.0)/320.0, (449.40673503982543-320.0)/320.0),
-            XY::new((384.6478386647359-320.0)/320.0, (449.8442034502077-320.0)/320.0),
-            XY::new((394.6517255432227-320.0)/320.0, (450.2829704185624-320.0)/320.0),
-            XY::new((404.6334469794151-320.0)/320.0, (450.7207652183954-320.0)/320.0),
-            XY::new((414.63110905350186-320.0)/320.0, (451.15925916901324-320.0)/320.0),
-            XY::new((424.61552891923066-320.0)/320.0, (451.5971723210189-320.0)/320.0),
-            XY::new((434.6139886458368-320.0)/320.0, (452.03570125639635-320.0)/320.0),
-            XY::new((444.59960412796636-320.0)/320.0, (452.4736668477178-320.0)/320.0),
-            XY::new((454.5866056623381-320.0)/320.0, (452.9116932308043-320.0)/320.0),
-            XY::new((464.5868865135417-320.0)/320.0, (453.3503020400676-320.0)/320.0),
-            XY::new((474.56021155757117-320.0)/320.0, (453.78772857708645-320.0)/320.0),
-            XY::new((484.5605423461566-320.0)/320.0, (454.2263395765858-320.0)/320.0),
-            XY::new((494.55012876796536-320.0)/320.0, (454.6644793319283-320.0)/320.0),
-            XY::new((494.87691896100296-320.0)/320.0, (446.8664635248715-320.0)/320.0),
-            XY::new((492.86049232522055-320.0)/320.0, (437.0723912939284-320.0)/320.0),
-            XY::new((490.8460185825825-320.0)/320.0, (427.287804543972-320.0)/320.0),
-            XY::new((488.8292851334554-320.0)/320.0, (417.49224207678344-320.0)/320.0),
-            XY::new((486.80992205807706-320.0)/320.0, (407.68390713923145-320.0)/320.0),
-            XY::new((484.7961384020746-320.0)/320.0, (397.90267223864794-320.0)/320.0),
-            XY::new((482.77965530753136-320.0)/320.0, (388.108325779438-320.0)/320.0),
-            XY::new((490.10236619280477-320.0)/320.0, (382.671909525714-320.0)/320.0),
-            XY::new((498.9056405065494-320.0)/320.0, (377.94962330443377-320.0)/320.0),
-            XY::new((507.71912332861393-320.0)/320.0, (373.2218609959091-320.0)/320.0),
-            XY::new((516.5365659133004-320.0)/320.0, (368.4919745763091-320.0)/320.0),
-            XY::new((525.3511385417223-320.0)/320.0, (363.76362766967213-320.0)/320.0),
-            XY::new((534.1631563428091-320.0)/320.0, (359.0366512333276-320.0)/320.0),
-            XY::new((542.9707644620648-320.0)/320.0, (354.31204025544866-320.0)/320.0),
-            XY::new((551.7894364258973-320.0)/320.0, (349.581494367565-320.0)/320.0),
-            XY::new((558.5002097189426-320.0)/320.0, (343.34600468724966-320.0)/320.0),
-            XY::new((560.1981483441778-320.0)/320.0, (333.4885276685236-320.0)/320.0),
-            XY::new((561.8946463842876-320.0)/320.0, (323.6394140467746-320.0)/320.0),
-            XY::new((563.5935670647959-320.0)/320.0, (313.7762356516014-320.0)/320.0),
-            XY::new((565.2888551860233-320.0)/320.0, (303.9341462811426-320.0)/320.0),
-            XY::new((566.986816410441-320.0)/320.0, (294.0765380616067-320.0)/320.0),
-            XY::new((568.6851993346936-320.0)/320.0, (284.216481640251-320.0)/320.0),
-            XY::new((570.3818083678998-320.0)/320.0, (274.3667236419151-320.0)/320.0),
-            XY::new((572.0803967439933-320.0)/320.0, (264.50547445848315-320.0)/320.0),
-            XY::new((573.777053134625-320.0)/320.0, (254.65544152398252-320.0)/320.0),
-            XY::new((575.4758850303479-320.0)/320.0, (244.79277857381385-320.0)/320.0),
-            XY::new((570.2217169562355-320.0)/320.0, (237.94931115303189-320.0)/320.0),
-            XY::new((561.8755188350187-320.0)/320.0, (232.45956586474495-320.0)/320.0),
-            XY::new((553.5152001932875-320.0)/320.0, (226.96053274745645-320.0)/320.0),
-            XY::new((545.1578931833646-320.0)/320.0, (221.46348054306873-320.0)/320.0),
-            XY::new((536.8047479146899-320.0)/320.0, (215.96916574067836-320.0)/320.0),
-            XY::new((528.4624964878458-320.0)/320.0, (210.48201640644402-320.0)/320.0),
-            XY::new((520.0922142186755-320.0)/320.0, (204.97642967324646-320.0)/320.0),
-            XY::new((511.7473912317637-320.0)/320.0, (199.48758888506381-320.0)/320.0),
-            XY::new((503.3959233940259-320.0)/320.0, (193.9943774195999-320.0)/320.0),
-            XY::new((495.0370349158911-320.0)/320.0, (188.496284998153-320.0)/320.0),
-            XY::new((486.68387523943966-320.0)/320.0, (183.00196071898972-320.0)/320.0),
-            XY::new((479.88097189285327-320.0)/320.0, (184.51403749675956-320.0)/320.0),
-            XY::new((474.65270513202995-320.0)/320.0, (193.02617428917438-320.0)/320.0),
-            XY::new((469.4189702923377-320.0)/320.0, (201.54721365627665-320.0)/320.0),
-            XY::new((464.1838229522109-320.0)/320.0, (210.07055271416903-320.0)/320.0),
-            XY::new((458.9467167357925-320.0)/320.0, (218.59708101693286-320.0)/320.0),
-            XY::new((453.7117740557296-320.0)/320.0, (227.12008686794434-320.0)/320.0),
-            XY::new((448.481169902263-320.0)/320.0, (235.63602916739-320.0)/320.0),
-            XY::new((443.244965611957-320.0)/320.0, (244.1610890449956-320.0)/320.0),
-            XY::new((438.01441222953144-320.0)/320.0, (252.67694868415128-320.0)/320.0),
-            XY::new((432.78131057333667-320.0)/320.0, (261.1969571657246-320.0)/320.0),
-            XY::new((427.54280935139104-320.0)/320.0, (269.7257566758344-320.0)/320.0),
-            XY::new((422.31108725536615-320.0)/320.0, (278.2435190966353-320.0)/320.0),
-            XY::new((412.71886610977344-320.0)/320.0, (277.28455780680633-320.0)/320.0),
-            XY::new((402.8373160569772-320.0)/320.0, (275.724313061628-320.0)/320.0),
-            XY::new((392.9589946918129-320.0)/320.0, (274.1645781092336-320.0)/320.0),
-            XY::new((383.0847912188092-320.0)/320.0, (272.6054933503383-320.0)/320.0),
-            XY::new((373.20876444700116-320.0)/320.0, (271.0461207021581-320.0)/320.0),
-            XY::new((363.32000667916145-320.0)/320.0, (269.4847378967097-320.0)/320.0),
-            XY::new((353.4500020095977-320.0)/320.0, (267.9263161067786-320.0)/320.0),
-            XY::new((343.572348442204-320.0)/320.0, (266.36668659613747-320.0)/320.0),
-            XY::new((333.7033881291263-320.0)/320.0, (264.8084297045989-320.0)/320.0),
-            XY::new((323.80676251649857-320.0)/320.0, (263.2458046078682-320.0)/320.0),
-            XY::new((313.9384826645057-320.0)/320.0, (261.68765515755354-320.0)/320.0),
-            XY::new((304.05744046642394-320.0)/320.0, (260.1274905999617-320.0)/320.0),
-            XY::new((294.1799965605169-320.0)/320.0, (258.5678941937658-320.0)/320.0),
-            XY::new((284.30241054181533-320.0)/320.0, (257.0082753487077-320.0)/320.0),
-            XY::new((281.67261315410724-320.0)/320.0, (251.59031652474368-320.0)/320.0),
-            XY::new((285.89248548581963-320.0)/320.0, (242.53980086593947-320.0)/320.0),
-            XY::new((290.12300952424994-320.0)/320.0, (233.46644009930606-320.0)/320.0),
-            XY::new((294.34824738636235-320.0)/320.0, (224.4044167897755-320.0)/320.0),
-            XY::new((298.5695799398818-320.0)/320.0, (215.35076933946402-320.0)/320.0),
-            XY::new((302.8014110590084-320.0)/320.0, (206.2746052287057-320.0)/320.0),
-            XY::new((307.02833013172494-320.0)/320.0, (197.20897616485308-320.0)/320.0),
-            XY::new((311.25167990284535-320.0)/320.0, (188.1510023136343-320.0)/320.0),
-            XY::new((315.477091912122-320.0)/320.0, (179.0886055042647-320.0)/320.0),
-            XY::new((322.5185452313599-320.0)/320.0, (173.31612993389717-320.0)/320.0),
-            XY::new((332.1281548142433-320.0)/320.0, (170.57052719593048-320.0)/320.0),
-            XY::new((341.7477750694525-320.0)/320.0, (167.82206426587072-320.0)/320.0),
-            XY::new((351.35394579559033-320.0)/320.0, (165.07744405840276-320.0)/320.0),
-            XY::new((360.9804516411077-320.0)/320.0, (162.32701381682637-320.0)/320.0),
-            XY::new((370.5806767926606-320.0)/320.0, (159.58409234495412-320.0)/320.0),
-            XY::new((380.20394744937335-320.0)/320.0, (156.8345864430362-320.0)/320.0),
-            XY::new((389.8278150400147-320.0)/320.0, (154.08490998856723-320.0)/320.0),
-            XY::new((399.4374666986987-320.0)/320.0, (151.33929522894323-320.0)/320.0),
-            XY::new((409.0508004155836-320.0)/320.0, (148.5926284526904-320.0)/320.0),
-            XY::new((418.6635300848866-320.0)/320.0, (145.84613426146097-320.0)/320.0),
-            XY::new((428.27505047451996-320.0)/320.0, (143.09998557870858-320.0)/320.0),
-            XY::new((428.38405254576355-320.0)/320.0, (139.61956271342933-320.0)/320.0),
-            XY::new((419.2966176653699-320.0)/320.0, (135.4253619994015-320.0)/320.0),
-            XY::new((410.2222808239476-320.0)/320.0, (131.23720653412965-320.0)/320.0),
-            XY::new((401.1354800985082-320.0)/320.0, (127.04329850700378-320.0)/320.0),
-            XY::new((392.05854604995534-320.0)/320.0, (122.85394433074862-320.0)/320.0),
-            XY::new((382.9792313940204-320.0)/320.0, (118.66349141262481-320.0)/320.0),
-            XY::new((373.89506288138546-320.0)/320.0, (114.47079825294713-320.0)/320.0),
-            XY::new((364.81554594290174-320.0)/320.0, (110.28025197364695-320.0)/320.0),
-            XY::new((355.73933972466307-320.0)/320.0, (106.09123371907526-320.0)/320.0),
-            XY::new((346.6587236645955-320.0)/320.0, (101.90018015289024-320.0)/320.0),
-            XY::new((337.5819794803738-320.0)/320.0, (97.71091360632636-320.0)/320.0),
-            XY::new((328.4924894100841-320.0)/320.0, (93.51576434311573-320.0)/320.0),
-            XY::new((319.4237535852153-320.0)/320.0, (89.33019396240707-320.0)/320.0),
-            XY::new((310.33786180836614-320.0)/320.0, (85.13670545001514-320.0)/320.0),
-            XY::new((300.45047208829783-320.0)/320.0, (85.37927180842962-320.0)/320.0),
-            XY::new((290.471361745982-320.0)/320.0, (86.0731834547496-320.0)/320.0),
-            XY::new((280.49739099598446-320.0)/320.0, (86.76673771219976-320.0)/320.0),
-            XY::new((270.51559717184864-320.0)/320.0, (87.46083595824894-320.0)/320.0),
-            XY::new((260.5528499498141-320.0)/320.0, (88.15360977170167-320.0)/320.0),
-            XY::new((250.5789058727678-320.0)/320.0, (88.84716217441019-320.0)/320.0),
-            XY::new((240.60059378752703-320.0)/320.0, (89.54101831278786-320.0)/320.0),
-            XY::new((230.6140164150761-320.0)/320.0, (90.23544918968014-320.0)/320.0),
-            XY::new((220.6393109389851-320.0)/320.0, (90.92905453735534-320.0)/320.0),
-            XY::new((210.6638540778108-320.0)/320.0, (91.62271213366216-320.0)/320.0),
-            XY::new((200.68738048863088-320.0)/320.0, (92.31644042959852-320.0)/320.0),
-            XY::new((190.70807765610516-320.0)/320.0, (93.01036546099931-320.0)/320.0),
-            XY::new((180.73998809765908-320.0)/320.0, (93.70351076142106-320.0)/320.0),
-            XY::new((170.76098135384382-320.0)/320.0, (94.39741520387179-320.0)/320.0),
-            XY::new((160.78148931008764-320.0)/320.0, (95.0913533923449-320.0)/320.0),
-            XY::new((157.57587109910673-320.0)/320.0, (87.59293196402723-320.0)/320.0),
-            XY::new((156.37839531895588-320.0)/320.0, (77.67098978563445-320.0)/320.0),
-            XY::new((155.17937348107807-320.0)/320.0, (67.7362374146469-320.0)/320.0),
-            XY::new((153.9827174951788-320.0)/320.0, (57.8210878171958-320.0)/320.0),
-            XY::new((152.78390789031982-320.0)/320.0, (47.88809394836426-320.0)/320.0),
-            XY::new((151.58598042093217-320.0)/320.0, (37.96240920200944-320.0)/320.0)),
-
-        vec!(XY::new((131.26511375309482-320.0)/320.0, (315.0509064777915-320.0)/320.0),
-            XY::new((140.92901787525858-320.0)/320.0, (317.57127652786323-320.0)/320.0),
-            XY::new((149.6569206685644-320.0)/320.0, (322.3559301834826-320.0)/320.0),
-            XY::new((156.5513058758124-320.0)/320.0, (329.5658255668347-320.0)/320.0),
-            XY::new((162.9627577706694-320.0)/320.0, (337.2363098888437-320.0)/320.0),
-            XY::new((170.5306071453191-320.0)/320.0, (343.7344796842716-320.0)/320.0),
-            XY::new((179.2024018301652-320.0)/320.0, (348.7043988451373-320.0)/320.0),
-            XY::new((188.5643308689259-320.0)/320.0, (352.1849040246289-320.0)/320.0),
-            XY::new((198.25779532841642-320.0)/320.0, (354.624149696572-320.0)/320.0),
-            XY::new((208.1059813770125-320.0)/320.0, (356.3279409400844-320.0)/320.0),
-            XY::new((218.04702812788787-320.0)/320.0, (357.46799386085235-320.0)/320.0),
-            XY::new((228.02886006431888-320.0)/320.0, (358.1366280375246-320.0)/320.0),
-            XY::new((238.02431083459174-320.0)/320.0, (358.3771046745486-320.0)/320.0),
-            XY::new((248.02387966827865-320.0)/320.0, (358.1792819833367-320.0)/320.0),
-            XY::new((257.9873912388147-320.0)/320.0, (357.42068505432877-320.0)/320.0),
-            XY::new((267.87686670078534-320.0)/320.0, (357.6107422371159-320.0)/320.0),
-            XY::new((277.2440465728077-320.0)/320.0, (361.07897707828346-320.0)/320.0),
-            XY::new((286.2429173938034-320.0)/320.0, (365.4333766419324-320.0)/320.0),
-            XY::new((295.05376953125-320.0)/320.0, (370.1661328125-320.0)/320.0),
-            XY::new((303.75345794772505-320.0)/320.0, (375.08855516323695-320.0)/320.0),
-            XY::new((312.4015631908178-320.0)/320.0, (380.1050769984722-320.0)/320.0),
-            XY::new((321.0423497447869-320.0)/320.0, (385.14467850018957-320.0)/320.0),
-            XY::new((329.71655453010294-320.0)/320.0, (390.13356017420114-320.0)/320.0),
-            XY::new((338.4587140099776-320.0)/320.0, (394.9573447813958-320.0)/320.0),
-            XY::new((347.4112398244607-320.0)/320.0, (399.4285239807649-320.0)/320.0),
-            XY::new((356.8342694118712-320.0)/320.0, (402.72911629961806-320.0)/320.0),
-            XY::new((365.8541223530751-320.0)/320.0, (406.3194980073069-320.0)/320.0),
-            XY::new((370.73773147592203-320.0)/320.0, (414.96126267565995-320.0)/320.0),
-            XY::new((372.89458740234375-320.0)/320.0, (424.70194580078123-320.0)/320.0),
-            XY::new((373.45156429083204-320.0)/320.0, (434.672863501556-320.0)/320.0),
-            XY::new((372.570822738536-320.0)/320.0, (444.6233630668733-320.0)/320.0),
-            XY::new((368.9945853883517-320.0)/320.0, (453.90372824593913-320.0)/320.0),
-            XY::new((363.37497071268035-320.0)/320.0, (462.1624630321189-320.0)/320.0),
-            XY::new((357.0424622368999-320.0)/320.0, (469.8835512460396-320.0)/320.0),
-            XY::new((350.1725698737544-320.0)/320.0, (477.16001456528437-320.0)/320.0),
-            XY::new((342.7650427394011-320.0)/320.0, (483.88145981320645-320.0)/320.0),
-            XY::new((334.64046265250073-320.0)/320.0, (489.6963320222869-320.0)/320.0),
-            XY::new((325.323708929997-320.0)/320.0, (493.03481383670123-320.0)/320.0),
-            XY::new((315.39153368569913-320.0)/320.0, (491.9518843227625-320.0)/320.0),
-            XY::new((305.44356719825146-320.0)/320.0, (490.8595072931715-320.0)/320.0),
-            XY::new((295.50917682919965-320.0)/320.0, (489.77115102462585-320.0)/320.0),
-            XY::new((285.56719566466705-320.0)/320.0, (488.6633417580009-320.0)/320.0),
-            XY::new((275.6362328691625-320.0)/320.0, (487.5251197022956-320.0)/320.0),
-            XY::new((265.69647811613396-320.0)/320.0, (486.3413168974128-320.0)/320.0),
-            XY::new((255.78587077888758-320.0)/320.0, (485.1000861012332-320.0)/320.0),
-            XY::new((245.8776756726287-320.0)/320.0, (483.7732740030717-320.0)/320.0),
-            XY::new((235.97173461563887-320.0)/320.0, (482.3159566575289-320.0)/320.0),
-            XY::new((226.1279901677264-320.0)/320.0, (480.63737284536825-320.0)/320.0),
-            XY::new((216.3292834209712-320.0)/320.0, (478.60792654444435-320.0)/320.0),
-            XY::new((206.47337188696721-320.0)/320.0, (479.8895512817311-320.0)/320.0),
-            XY::new((197.1808344475925-320.0)/320.0, (483.53415978446606-320.0)/320.0),
-            XY::new((188.31954863039545-320.0)/320.0, (488.1622994491575-320.0)/320.0),
-            XY::new((179.71268812102497-320.0)/320.0, (493.27616276830213-320.0)/320.0),
-            XY::new((171.25883253299315-320.0)/320.0, (498.60666806579434-320.0)/320.0),
-            XY::new((162.831884432293-320.0)/320.0, (503.980950287527-320.0)/320.0),
-            XY::new((154.29948086307238-320.0)/320.0, (509.1980421414183-320.0)/320.0),
-            XY::new((145.45352427830687-320.0)/320.0, (513.8654340807465-320.0)/320.0),
-            XY::new((135.87257038543814-320.0)/320.0, (516.3928724640084-320.0)/320.0),
-            XY::new((127.21195472654755-320.0)/320.0, (511.9264516031072-320.0)/320.0),
-            XY::new((121.85527539119241-320.0)/320.0, (503.53660505544985-320.0)/320.0),
-            XY::new((118.37768768310548-320.0)/320.0, (494.1724325561524-320.0)/320.0),
-            XY::new((115.96984445497976-320.0)/320.0, (484.4687719291368-320.0)/320.0),
-            XY::new((114.305765977454-320.0)/320.0, (474.59994042678545-320.0)/320.0),
-            XY::new((113.29845423042774-320.0)/320.0, (464.6636698299646-320.0)/320.0),
-            XY::new((109.57576548576355-320.0)/320.0, (455.7138435306307-320.0)/320.0),
-            XY::new((100.52194824934007-320.0)/320.0, (451.7599533522839-320.0)/320.0),
-            XY::new((90.56022923231126-320.0)/320.0, (451.00694374792863-320.0)/320.0),
-            XY::new((80.58849695861339-320.0)/320.0, (451.6763456336047-320.0)/320.0),
-            XY::new((74.31310149490834-320.0)/320.0, (458.45836153804953-320.0)/320.0),
-            XY::new((69.84388030827046-320.0)/320.0, (467.40082951055473-320.0)/320.0),
-            XY::new((66.7062226986885-320.0)/320.0, (476.8755230092781-320.0)/320.0),
-            XY::new((67.73630612373353-320.0)/320.0, (486.57628110670487-320.0)/320.0),
-            XY::new((76.44554736375808-320.0)/320.0, (490.6982918056299-320.0)/320.0),
-            XY::new((85.88001259932179-320.0)/320.0, (493.6172311852826-320.0)/320.0),
-            XY::new((92.24719543538987-320.0)/320.0, (501.2023964199424-320.0)/320.0),
-            XY::new((95.66287288354609-320.0)/320.0, (510.57652142530833-320.0)/320.0),
-            XY::new((97.99636016845703-320.0)/320.0, (520.2985559082032-320.0)/320.0),
-            XY::new((100.67944702866487-320.0)/320.0, (529.9319808037206-320.0)/320.0),
-            XY::new((105.21198466874658-320.0)/320.0, (538.8087948579621-320.0)/320.0),
-            XY::new((111.06853412628173-320.0)/320.0, (546.918419585228-320.0)/320.0),
-            XY::new((118.45288740046323-320.0)/320.0, (553.6025316155608-320.0)/320.0),
-            XY::new((127.63609568119048-320.0)/320.0, (557.4013230150938-320.0)/320.0),
-            XY::new((137.56669964355416-320.0)/320.0, (557.3369272299382-320.0)/320.0),
-            XY::new((147.15548113159835-320.0)/320.0, (554.5018098327308-320.0)/320.0),
-            XY::new((156.37618358232083-320.0)/320.0, (550.6551283065905-320.0)/320.0),
-            XY::new((165.34254065811635-320.0)/320.0, (546.2534758389927-320.0)/320.0),
-            XY::new((174.11251468122006-320.0)/320.0, (541.415652560722-320.0)/320.0),
-            XY::new((182.6101090556383-320.0)/320.0, (536.1489641786553-320.0)/320.0),
-            XY::new((190.93005824527353-320.0)/320.0, (530.6449471768132-320.0)/320.0),
-            XY::new((200.4141154417592-320.0)/320.0, (527.5471889929387-320.0)/320.0),
-            XY::new((210.23217933478549-320.0)/320.0, (525.6344396364078-320.0)/320.0),
-            XY::new((220.14704573958284-320.0)/320.0, (524.3858774587053-320.0)/320.0),
-            XY::new((230.12767009589254-320.0)/320.0, (523.6480277893967-320.0)/320.0),
-            XY::new((240.11328281350097-320.0)/320.0, (523.3947551551641-320.0)/320.0),
-            XY::new((250.10699984567282-320.0)/320.0, (523.6824599736678-320.0)/320.0),
-            XY::new((260.06803085754905-320.0)/320.0, (524.7024035400182-320.0)/320.0),
-            XY::new((269.7693387653399-320.0)/320.0, (527.0158662522678-320.0)/320.0),
-            XY::new((278.81507338557026-320.0)/320.0, (531.2641962611634-320.0)/320.0),
-            XY::new((287.17718462266913-320.0)/320.0, (536.7254030446081-320.0)/320.0),
-            XY::new((295.1445602416992-320.0)/320.0, (542.7566293334961-320.0)/320.0),
-            XY::new((302.9990124896773-320.0)/320.0, (548.9553290217549-320.0)/320.0),
-            XY::new((310.9421083081105-320.0)/320.0, (555.0335643610316-320.0)/320.0),
-            XY::new((319.1302134237718-320.0)/320.0, (560.7789433192369-320.0)/320.0),
-            XY::new((327.64453691309535-320.0)/320.0, (566.0010686586859-320.0)/320.0),
-            XY::new((336.5732254316577-320.0)/320.0, (570.4872400928521-320.0)/320.0),
-            XY::new((346.3526702884678-320.0)/320.0, (572.441955945678-320.0)/320.0),
-            XY::new((356.29155699589126-320.0)/320.0, (571.577642778252-320.0)/320.0),
-            XY::new((365.852380233407-320.0)/320.0, (568.7312194561958-320.0)/320.0),
-            XY::new((374.92011781899913-320.0)/320.0, (564.5087451193069-320.0)/320.0),
-            XY::new((383.43425271407705-320.0)/320.0, (559.291268630512-320.0)/320.0),
-            XY::new((391.4077214155755-320.0)/320.0, (553.259007285678-320.0)/320.0),
-            XY::new((398.7695502073702-320.0)/320.0, (546.4957959784427-320.0)/320.0),
-            XY::new((405.32889190673825-320.0)/320.0, (538.9656799316406-320.0)/320.0),
-            XY::new((410.42196675115144-320.0)/320.0, (530.3906495790695-320.0)/320.0),
-            XY::new((412.965682430357-320.0)/320.0, (520.8330011479383-320.0)/320.0),
-            XY::new((420.7315711813757-320.0)/320.0, (514.7140486598772-320.0)/320.0),
-            XY::new((430.14056465703914-320.0)/320.0, (511.3840665727282-320.0)/320.0),
-            XY::new((439.9409752653597-320.0)/320.0, (509.3918884468183-320.0)/320.0),
-            XY::new((449.87370581411176-320.0)/320.0, (508.24490410890314-320.0)/320.0),
-            XY::new((459.85512891352755-320.0)/320.0, (507.8015982021496-320.0)/320.0),
-            XY::new((469.68678713396423-320.0)/320.0, (509.2021968174993-320.0)/320.0),
-            XY::new((478.36659194336505-320.0)/320.0, (514.084734425008-320.0)/320.0),
-            XY::new((485.740019789543-320.0)/320.0, (520.8379768570978-320.0)/320.0),
-            XY::new((491.77933544889095-320.0)/320.0, (528.7942465000599-320.0)/320.0),
-            XY::new((496.24203112948715-320.0)/320.0, (537.7196948016096-320.0)/320.0),
-            XY::new((498.51757092952727-320.0)/320.0, (547.415043592453-320.0)/320.0),
-            XY::new((505.2062269751762-320.0)/320.0, (554.4500231084298-320.0)/320.0),
-            XY::new((514.5247788898263-320.0)/320.0, (558.0305135305948-320.0)/320.0),
-            XY::new((524.2687777480301-320.0)/320.0, (560.2918849193686-320.0)/320.0),
-            XY::new((534.1401196239598-320.0)/320.0, (561.8686397238984-320.0)/320.0),
-            XY::new((544.0771610780042-320.0)/320.0, (563.0178061021828-320.0)/320.0),
-            XY::new((554.0403335946274-320.0)/320.0, (563.8671959267999-320.0)/320.0),
-            XY::new((564.0270525569703-320.0)/320.0, (564.4933345301918-320.0)/320.0),
-            XY::new((574.0091507158162-320.0)/320.0, (564.9437009916293-320.0)/320.0),
-            XY::new((584.0063990402222-320.0)/320.0, (565.2528347015381-320.0)/320.0),
-            XY::new((594.0000882771358-320.0)/320.0, (565.4441697577624-320.0)/320.0),
-            XY::new((599.16959073678-320.0)/320.0, (560.3432527542114-320.0)/320.0),
-            XY::new((598.2018319914956-320.0)/320.0, (550.4235823059082-320.0)/320.0),
-            XY::new((593.6742921685334-320.0)/320.0, (541.6196113586426-320.0)/320.0),
-            XY::new((585.2883668076992-320.0)/320.0, (536.3433798593283-320.0)/320.0),
-            XY::new((575.8616346140205-320.0)/320.0, (533.0017957002669-320.0)/320.0),
-            XY::new((566.5862304874812-320.0)/320.0, (529.2791989989311-320.0)/320.0),
-            XY::new((558.503248090744-320.0)/320.0, (523.4587436628342-320.0)/320.0),
-            XY::new((552.7666707029194-320.0)/320.0, (515.3361743126624-320.0)/320.0),
-            XY::new((548.8571369932953-320.0)/320.0, (506.1293565987385-320.0)/320.0),
-            XY::new((545.9588496160508-320.0)/320.0, (496.5545475757122-320.0)/320.0),
-            XY::new((543.074934540391-320.0)/320.0, (486.9880035085976-320.0)/320.0),
-            XY::new((536.4788210920524-320.0)/320.0, (480.15575611863284-320.0)/320.0),
-            XY::new((526.6125309644464-320.0)/320.0, (478.54778237007383-320.0)/320.0),
-            XY::new((516.6706010373018-320.0)/320.0, (477.4689690643409-320.0)/320.0),
-            XY::new((506.71719391341185-320.0)/320.0, (476.51043884568674-320.0)/320.0),
-            XY::new((496.762486451248-320.0)/320.0, (475.54157392145544-320.0)/320.0),
-            XY::new((486.81411531679333-320.0)/320.0, (474.4443866744638-320.0)/320.0),
-            XY::new((476.9315398659303-320.0)/320.0, (472.978328003778-320.0)/320.0),
-            XY::new((467.52138030319475-320.0)/320.0, (469.7593194087967-320.0)/320.0),
-            XY::new((459.5387318042945-320.0)/320.0, (463.76475892316546-320.0)/320.0),
-            XY::new((453.01821097267486-320.0)/320.0, (456.2096213033068-320.0)/320.0),
-            XY::new((447.88374722003937-320.0)/320.0, (447.6474652290344-320.0)/320.0),
-            XY::new((444.1610349077091-320.0)/320.0, (438.3718424207764-320.0)/320.0),
-            XY::new((441.95188188422253-320.0)/320.0, (428.6360114337091-320.0)/320.0),
-            XY::new((442.31202074657546-320.0)/320.0, (418.7167274933134-320.0)/320.0),
-            XY::new((446.34968134053054-320.0)/320.0, (409.6143252386898-320.0)/320.0),
-            XY::new((451.93989202345415-320.0)/320.0, (401.3310153960671-320.0)/320.0),
-            XY::new((458.02217634963176-320.0)/320.0, (393.37420519005957-320.0)/320.0),
-            XY::new((463.9519013234868-320.0)/320.0, (385.34032551706423-320.0)/320.0),
-            XY::new((469.0467738823801-320.0)/320.0, (376.7327038992867-320.0)/320.0),
-            XY::new((471.84501306869106-320.0)/320.0, (367.20328329674476-320.0)/320.0),
-            XY::new((470.04598614378835-320.0)/320.0, (357.49477307838384-320.0)/320.0),
-            XY::new((464.00614221583965-320.0)/320.0, (349.56931130150156-320.0)/320.0),
-            XY::new((455.66467627967796-320.0)/320.0, (344.17251597795814-320.0)/320.0),
-            XY::new((445.9189873267524-320.0)/320.0, (342.1412756386027-320.0)/320.0),
-            XY::new((435.9342150453362-320.0)/320.0, (342.58646968183575-320.0)/320.0),
-            XY::new((426.010944378165-320.0)/320.0, (343.7712812782888-320.0)/320.0),
-            XY::new((416.09420510962605-320.0)/320.0, (343.15175838857886-320.0)/320.0),
-            XY::new((408.92785163884054-320.0)/320.0, (336.5971363054402-320.0)/320.0),
-            XY::new((407.7117356702837-320.0)/320.0, (326.79808338605096-320.0)/320.0),
-            XY::new((409.71842452469633-320.0)/320.0, (317.0169943341031-320.0)/320.0),
-            XY::new((412.5985939886328-320.0)/320.0, (307.43641638433564-320.0)/320.0),
-            XY::new((415.41891738698814-320.0)/320.0, (297.84139102498074-320.0)/320.0),
-            XY::new((417.62791387691163-320.0)/320.0, (288.0858195432089-320.0)/320.0),
-            XY::new((418.84495545444776-320.0)/320.0, (278.16242144009567-320.0)/320.0),
-            XY::new((423.0929623899632-320.0)/320.0, (269.2849792240327-320.0)/320.0),
-            XY::new((431.32519964295204-320.0)/320.0, (263.7203077811422-320.0)/320.0),
-            XY::new((440.84129056768984-320.0)/320.0, (260.726655537586-320.0)/320.0),
-            XY::new((450.69594880564136-320.0)/320.0, (259.04438837219027-320.0)/320.0),
-            XY::new((460.6361927542347-320.0)/320.0, (257.99104820762295-320.0)/320.0),
-            XY::new((470.6021484867047-320.0)/320.0, (257.12732244635777-320.0)/320.0),
-            XY::new((480.53218389718126-320.0)/320.0, (256.0261533652357-320.0)/320.0),
-            XY::new((490.30019528607835-320.0)/320.0, (253.9407653801795-320.0)/320.0),
-            XY::new((498.650291725247-320.0)/320.0, (248.64187480333612-320.0)/320.0),
-            XY::new((502.4978423199323-320.0)/320.0, (239.5688494147062-320.0)/320.0),
-            XY::new((502.11333860414703-320.0)/320.0, (229.61791014267786-320.0)/320.0),
-            XY::new((499.5180777364224-320.0)/320.0, (219.96341605685652-320.0)/320.0),
-            XY::new((495.7170626532122-320.0)/320.0, (210.72399570481545-320.0)/320.0),
-            XY::new((491.7550762656052-320.0)/320.0, (201.55439076134792-320.0)/320.0),
-            XY::new((489.7153452762217-320.0)/320.0, (191.7930917863478-320.0)/320.0),
-            XY::new((489.76656959670595-320.0)/320.0, (181.80814763021337-320.0)/320.0),
-            XY::new((491.84305707089607-320.0)/320.0, (172.04917762546103-320.0)/320.0),
-            XY::new((495.7369261628389-320.0)/320.0, (162.8373841627501-320.0)/320.0),
-            XY::new((501.1083874481916-320.0)/320.0, (154.4252059247531-320.0)/320.0),
-            XY::new((507.522669982848-320.0)/320.0, (146.74471078621036-320.0)/320.0),
-            XY::new((514.1249019894063-320.0)/320.0, (139.24962290174315-320.0)/320.0),
-            XY::new((520.6140324543348-320.0)/320.0, (131.63727751002983-320.0)/320.0),
-            XY::new((526.6399690127496-320.0)/320.0, (123.66254814425295-320.0)/320.0),
-            XY::new((531.4986972607132-320.0)/320.0, (114.94159537929968-320.0)/320.0),
-            XY::new((533.1311626775841-320.0)/320.0, (105.19459291731008-320.0)/320.0),
-            XY::new((528.4706996488292-320.0)/320.0, (96.56972821961156-320.0)/320.0),
-            XY::new((519.7238307134315-320.0)/320.0, (92.05718170751047-320.0)/320.0),
-            XY::new((509.7595252533772-320.0)/320.0, (92.13344236785036-320.0)/320.0),
-            XY::new((500.05634454237673-320.0)/320.0, (94.46504726722037-320.0)/320.0),
-            XY::new((490.67890225094015-320.0)/320.0, (97.93989804750235-320.0)/320.0),
-            XY::new((481.5775609001671-320.0)/320.0, (102.09712228326374-320.0)/320.0),
-            XY::new((472.7086939373033-320.0)/320.0, (106.70240368993095-320.0)/320.0),
-            XY::new((464.0026614607312-320.0)/320.0, (111.63445093841293-320.0)/320.0),
-            XY::new((455.4357347146826-320.0)/320.0, (116.77358653333721-320.0)/320.0),
-            XY::new((447.2575969526638-320.0)/320.0, (122.27091407464818-320.0)/320.0),
-            XY::new((447.0157528719958-320.0)/320.0, (132.20725107496605-320.0)/320.0),
-            XY::new((448.62128616900145-320.0)/320.0, (142.07341942079861-320.0)/320.0),
-            XY::new((450.8960936307907-320.0)/320.0, (151.80571200370787-320.0)/320.0),
-            XY::new((453.70076431977566-320.0)/320.0, (161.40082159457728-320.0)/320.0),
-            XY::new((457.182547818359-320.0)/320.0, (170.77149359385513-320.0)/320.0),
-            XY::new((458.1073041110909-320.0)/320.0, (180.32009514411183-320.0)/320.0),
-            XY::new((453.06201330444003-320.0)/320.0, (188.91059878460567-320.0)/320.0),
-            XY::new((446.4875857922093-320.0)/320.0, (196.44549758502777-320.0)/320.0),
-            XY::new((439.23677296399916-320.0)/320.0, (203.3236427218144-320.0)/320.0),
-            XY::new((431.54300793093284-320.0)/320.0, (209.6973470719182-320.0)/320.0),
-            XY::new((423.463092779568-320.0)/320.0, (215.6002130398041-320.0)/320.0),
-            XY::new((414.9858129882813-320.0)/320.0, (220.9065283203125-320.0)/320.0),
-            XY::new((405.8928288290522-320.0)/320.0, (225.02683946062112-320.0)/320.0),
-            XY::new((397.7930834549293-320.0)/320.0, (230.24810728771612-320.0)/320.0),
-            XY::new((393.3491494068876-320.0)/320.0, (239.18173079879955-320.0)/320.0),
-            XY::new((390.11150501810476-320.0)/320.0, (248.65545964533692-320.0)/320.0),
-            XY::new((387.200691333607-320.0)/320.0, (258.21514254269334-320.0)/320.0),
-            XY::new((384.1545009821054-320.0)/320.0, (267.73508038584635-320.0)/320.0),
-            XY::new((380.5223486170173-320.0)/320.0, (277.0520439986884-320.0)/320.0),
-            XY::new((375.5695706080878-320.0)/320.0, (285.70890384498284-320.0)/320.0),
-            XY::new((368.278752889171-320.0)/320.0, (292.50609403600794-320.0)/320.0),
-            XY::new((359.69889982879164-320.0)/320.0, (297.6282885020972-320.0)/320.0),
-            XY::new((350.57326541515647-320.0)/320.0, (301.681534449599-320.0)/320.0),
-            XY::new((341.08579134528526-320.0)/320.0, (304.88220534660854-320.0)/320.0),
-            XY::new((331.3818629620819-320.0)/320.0, (307.2269258169942-320.0)/320.0),
-            XY::new((321.46577946477964-320.0)/320.0, (308.5693484766675-320.0)/320.0),
-            XY::new((311.4856336161622-320.0)/320.0, (308.412349481032-320.0)/320.0),
-            XY::new((302.10964029207173-320.0)/320.0, (305.23369128229314-320.0)/320.0),
-            XY::new((296.2213029336929-320.0)/320.0, (297.23920729637143-320.0)/320.0),
-            XY::new((292.0093761162319-320.0)/320.0, (288.18436236352863-320.0)/320.0),
-            XY::new((288.33911088599666-320.0)/320.0, (278.8893183914825-320.0)/320.0),
-            XY::new((284.9363872770267-320.0)/320.0, (269.47738487005296-320.0)/320.0),
-            XY::new((281.6928253326567-320.0)/320.0, (260.0247118461869-320.0)/320.0),
-            XY::new((278.53454052358995-320.0)/320.0, (250.53800682181114-320.0)/320.0),
-            XY::new((275.41611312945565-320.0)/320.0, (241.0357689301946-320.0)/320.0),
-            XY::new((272.30052194469135-320.0)/320.0, (231.53250790235793-320.0)/320.0),
-            XY::new((269.1490211174008-320.0)/320.0, (222.03210884703728-320.0)/320.0),
-            XY::new((265.9302157879585-320.0)/320.0, (212.5753197175075-320.0)/320.0),
-            XY::new((262.57665205515326-320.0)/320.0, (203.13947184051764-320.0)/320.0),
-            XY::new((259.0231473524345-320.0)/320.0, (193.8021353837871-320.0)/320.0),
-            XY::new((255.1087652066095-320.0)/320.0, (184.60137300634085-320.0)/320.0),
-            XY::new((250.48749057687368-320.0)/320.0, (175.74144869005409-320.0)/320.0),
-            XY::new((246.1174210968254-320.0)/320.0, (166.75475344675112-320.0)/320.0),
-            XY::new((243.6664605209751-320.0)/320.0, (157.08581652550535-320.0)/320.0),
-            XY::new((244.71176715038717-320.0)/320.0, (147.22067814148963-320.0)/320.0),
-            XY::new((249.6387582888326-320.0)/320.0, (138.58739286089664-320.0)/320.0),
-            XY::new((256.6207563751936-320.0)/320.0, (131.43683914005754-320.0)/320.0),
-            XY::new((264.2402025592653-320.0)/320.0, (124.96902640196843-320.0)/320.0),
-            XY::new((273.4318602335412-320.0)/320.0, (121.33634124762918-320.0)/320.0),
-            XY::new((283.4009054134554-320.0)/320.0, (121.5548203345749-320.0)/320.0),
-            XY::new((293.30891735930464-320.0)/320.0, (122.90082144180894-320.0)/320.0),
-            XY::new((303.28481487057115-320.0)/320.0, (123.43409106710503-320.0)/320.0),
-            XY::new((313.05341579904723-320.0)/320.0, (121.52159256739819-320.0)/320.0),
-            XY::new((321.59167814646383-320.0)/320.0, (116.40649364082725-320.0)/320.0),
-            XY::new((328.2452640961355-320.0)/320.0, (108.96266720173108-320.0)/320.0),
-            XY::new((331.78857029392384-320.0)/320.0, (99.73349976593745-320.0)/320.0),
-            XY::new((329.30760841031554-320.0)/320.0, (90.23895806470304-320.0)/320.0),
-            XY::new((322.35890986986453-320.0)/320.0, (83.11470849058591-320.0)/320.0),
-            XY::new((313.74400430121113-320.0)/320.0, (78.09157114578804-320.0)/320.0),
-            XY::new((304.40876821339134-320.0)/320.0, (74.48191183589398-320.0)/320.0),
-            XY::new((294.735635378348-320.0)/320.0, (71.99859143655442-320.0)/320.0),
-            XY::new((284.84270851478914-320.0)/320.0, (70.57759903220344-320.0)/320.0),
-            XY::new((274.85940221103954-320.0)/320.0, (70.45672664726881-320.0)/320.0),
-            XY::new((265.3191709695442-320.0)/320.0, (73.19624227409398-320.0)/320.0),
-            XY::new((257.86039888627823-320.0)/320.0, (79.74290770711377-320.0)/320.0),
-            XY::new((252.58990536667406-320.0)/320.0, (88.24054883355275-320.0)/320.0),
-            XY::new((248.41810748644173-320.0)/320.0, (97.32196846501901-320.0)/320.0),
-            XY::new((244.50019486368635-320.0)/320.0, (106.5232725656056-320.0)/320.0),
-            XY::new((240.32248419858516-320.0)/320.0, (115.60389757061378-320.0)/320.0),
-            XY::new((235.531021485338-320.0)/320.0, (124.37882446065778-320.0)/320.0),
-            XY::new((230.00461219973164-320.0)/320.0, (132.70762114887418-320.0)/320.0),
-            XY::new((226.51809652576077-320.0)/320.0, (142.0267839141425-320.0)/320.0),
-            XY::new((225.71335009284346-320.0)/320.0, (151.980038233038-320.0)/320.0),
-            XY::new((226.50907054742797-320.0)/320.0, (161.94023342335132-320.0)/320.0),
-            XY::new((228.1657176105576-320.0)/320.0, (171.798254885228-320.0)/320.0),
-            XY::new((230.25019363792848-320.0)/320.0, (181.58911434290985-320.0)/320.0),
-            XY::new((232.47457267103715-320.0)/320.0, (191.32951942625922-320.0)/320.0),
-            XY::new((234.63417891731487-320.0)/320.0, (201.08906378270592-320.0)/320.0),
-            XY::new((236.54788563982817-320.0)/320.0, (210.9167389084754-320.0)/320.0),
-            XY::new((238.02681171590694-320.0)/320.0, (220.80480643231482-320.0)/320.0),
-            XY::new((238.8741629886627-320.0)/320.0, (230.7499515938759-320.0)/320.0),
-            XY::new((238.87016360640524-320.0)/320.0, (240.7478393945098-320.0)/320.0),
-            XY::new((237.77465855455026-320.0)/320.0, (250.68288083133757-320.0)/320.0),
-            XY::new((235.37026463972086-320.0)/320.0, (260.38703288906584-320.0)/320.0),
-            XY::new((231.56847613574936-320.0)/320.0, (269.62020208979493-320.0)/320.0),
-            XY::new((225.94086604912067-320.0)/320.0, (277.86503014173655-320.0)/320.0),
-            XY::new((217.9729592330754-320.0)/320.0, (283.79637012753636-320.0)/320.0),
-            XY::new((208.32272456691368-320.0)/320.0, (286.1240170512157-320.0)/320.0),
-            XY::new((198.3637239819579-320.0)/320.0, (285.3540877323365-320.0)/320.0),
-            XY::new((188.71790350285096-320.0)/320.0, (282.7743380404871-320.0)/320.0),
-            XY::new((179.35719251761213-320.0)/320.0, (279.23828469131604-320.0)/320.0),
-            XY::new((170.19296565653755-320.0)/320.0, (275.2525848161662-320.0)/320.0),
-            XY::new((161.0768702766576-320.0)/320.0, (271.1483262129252-320.0)/320.0),
-            XY::new((151.87050199477702-320.0)/320.0, (267.233488750825-320.0)/320.0),
-            XY::new((142.74916797624263-320.0)/320.0, (263.1904869233859-320.0)/320.0),
-            XY::new((134.98888480119348-320.0)/320.0, (256.9545610914599-320.0)/320.0),
-            XY::new((130.88401972820517-320.0)/320.0, (247.9633453164657-320.0)/320.0),
-            XY::new((130.48484557523858-320.0)/320.0, (237.9834582332417-320.0)/320.0),
-            XY::new((131.3905074102478-320.0)/320.0, (228.02829761146683-320.0)/320.0),
-            XY::new((132.11279140257915-320.0)/320.0, (218.06182664133885-320.0)/320.0),
-            XY::new((131.8925947943324-320.0)/320.0, (208.0794949053634-320.0)/320.0),
-            XY::new((130.79897115408386-320.0)/320.0, (198.13777643251058-320.0)/320.0),
-            XY::new((132.3837942957878-320.0)/320.0, (188.33124563455578-320.0)/320.0),
-            XY::new((137.96597743133026-320.0)/320.0, (180.12805235404346-320.0)/320.0),
-            XY::new((145.75688285443468-320.0)/320.0, (173.87352602327707-320.0)/320.0),
-            XY::new((154.05852624669205-320.0)/320.0, (168.28579755513462-320.0)/320.0),
-            XY::new((162.00333330560417-320.0)/320.0, (162.2199322991242-320.0)/320.0),
-            XY::new((168.85203727095796-320.0)/320.0, (154.96990560863458-320.0)/320.0),
-            XY::new((173.93859162386508-320.0)/320.0, (146.3824792437628-320.0)/320.0),
-            XY::new((178.94411203723865-320.0)/320.0, (137.74238024074452-320.0)/320.0),
-            XY::new((185.4218615159765-320.0)/320.0, (130.14177354722167-320.0)/320.0),
-            XY::new((192.59302966326476-320.0)/320.0, (123.16697233595886-320.0)/320.0),
-            XY::new((199.90839751568214-320.0)/320.0, (116.35875387533814-320.0)/320.0),
-            XY::new((206.9730160432635-320.0)/320.0, (109.30007267906228-320.0)/320.0),
-            XY::new((213.4255244737165-320.0)/320.0, (101.65064088712897-320.0)/320.0),
-            XY::new((218.84628084163649-320.0)/320.0, (93.26210192420884-320.0)/320.0),
-            XY::new((222.9122885347111-320.0)/320.0, (84.13187884083499-320.0)/320.0),
-            XY::new((225.45627404742407-320.0)/320.0, (74.47249542172517-320.0)/320.0),
-            XY::new((226.54243306048681-320.0)/320.0, (64.5483695324602-320.0)/320.0),
-            XY::new((226.3748621409759-320.0)/320.0, (54.546824327857465-320.0)/320.0),
-            XY::new((225.1432031459455-320.0)/320.0, (44.627044607715504-320.0)/320.0),
-            XY::new((222.17685983873903-320.0)/320.0, (35.11642269968986-320.0)/320.0),
-            XY::new((214.81029196270742-320.0)/320.0, (28.9955281893909-320.0)/320.0),
-            XY::new((205.56406536588227-320.0)/320.0, (32.19266009949846-320.0)/320.0),
-            XY::new((198.17529925525088-320.0)/320.0, (38.91392668454675-320.0)/320.0),
-            XY::new((191.91555733702145-320.0)/320.0, (46.68912388637662-320.0)/320.0),
-            XY::new((186.3986270311696-320.0)/320.0, (55.037136122751974-320.0)/320.0),
-            XY::new((181.53448276701724-320.0)/320.0, (63.76533214672497-320.0)/320.0),
-            XY::new((177.36431772721915-320.0)/320.0, (72.85537037680537-320.0)/320.0),
-            XY::new((174.2713930169672-320.0)/320.0, (82.34574762292439-320.0)/320.0),
-            XY::new((174.4310839133989-320.0)/320.0, (92.21407621459103-320.0)/320.0),
-            XY::new((170.80412548065186-320.0)/320.0, (101.41238986968993-320.0)/320.0),
-            XY::new((164.7037393092236-320.0)/320.0, (109.33223063573124-320.0)/320.0),
-            XY::new((157.7911415901035-320.0)/320.0, (116.53941973157227-320.0)/320.0),
-            XY::new((150.40428809762352-320.0)/320.0, (123.27992548723822-320.0)/320.0),
-            XY::new((142.68330270373264-320.0)/320.0, (129.63523106317035-320.0)/320.0),
-            XY::new((134.68584354121745-320.0)/320.0, (135.63408844490255-320.0)/320.0),
-            XY::new((126.41474615648374-320.0)/320.0, (141.27475581366988-320.0)/320.0),
-            XY::new((117.89585511363694-320.0)/320.0, (146.47489488455352-320.0)/320.0),
-            XY::new((109.03470302402972-320.0)/320.0, (151.12471185147763-320.0)/320.0),
-            XY::new((99.76543330347049-320.0)/320.0, (154.85355802377336-320.0)/320.0),
-            XY::new((91.3126025398563-320.0)/320.0, (159.91745607952367-320.0)/320.0),
-            XY::new((86.53120088212759-320.0)/320.0, (168.62562807149615-320.0)/320.0),
-            XY::new((84.44914064484875-320.0)/320.0, (178.38344372954924-320.0)/320.0),
-            XY::new((83.86541487893511-320.0)/320.0, (188.3531909368331-320.0)/320.0),
-            XY::new((84.18849609375-320.0)/320.0, (198.34947265625-320.0)/320.0),
-            XY::new((85.11774875880613-320.0)/320.0, (208.30448827682602-320.0)/320.0),
-            XY::new((86.48301853222348-320.0)/320.0, (218.21060479524039-320.0)/320.0),
-            XY::new((88.17799449534664-320.0)/320.0, (228.0614493331169-320.0)/320.0),
-            XY::new((90.13628230173418-320.0)/320.0, (237.87794650878482-320.0)/320.0),
-            XY::new((92.30300695188349-320.0)/320.0, (247.63415380038316-320.0)/320.0),
-            XY::new((94.54742474698156-320.0)/320.0, (257.3782382130623-320.0)/320.0),
-            XY::new((96.49651574685238-320.0)/320.0, (267.1800862503052-320.0)/320.0),
-            XY::new((99.02784637154662-320.0)/320.0, (276.86948620796204-320.0)/320.0),
-            XY::new((102.3010757611785-320.0)/320.0, (286.29683917999273-320.0)/320.0),
-            XY::new((106.5866952862883-320.0)/320.0, (295.3274883389473-320.0)/320.0),
-            XY::new((112.22741096530459-320.0)/320.0, (303.5639317035675-320.0)/320.0),
-            XY::new((119.59888242172192-320.0)/320.0, (310.29662617683414-320.0)/320.0),
-            XY::new((128.63035600765608-320.0)/320.0, (314.46245197296145-320.0)/320.0))
-    );
-
-    let mut shapes = vec!();
-    let mut rng = rand::rng();
-
-    for path in paths 
-    {
-        let path_xys = triangulator::calc(&path);
-        let mut path_verts = Vec::with_capacity(path_xys.len()*3);
-        for xy in path_xys
-        {
-            let rand_col = [rng.random::<f32>(), rng.random::<f32>(), rng.random::<f32>()];
-            
-            path_verts.push(Vertex { position: [xy.0.x, xy.0.y, 0.0], color: rand_col });
-            path_verts.push(Vertex { position: [xy.1.x, xy.1.y, 0.0], color: rand_col });
-            path_verts.push(Vertex { position: [xy.2.x, xy.2.y, 0.0], color: rand_col });
-        }
-        shapes.push(path_verts);
-    };
-
-    shapes
+use rand::Rng;
+use std::collections::HashMap;
+
+use graphics_test::triangulator;
+use graphics_test::booleans::{self, BooleanOp};
+use graphics_test::offset::{self, OffsetJoin};
+use graphics_test::simplify;
+use graphics_test::batch;
+use graphics_test::curves::{self, PathBuilder};
+use graphics_test::stroke::{self, LineCap, LineJoin, StrokeStyle};
+use graphics_test::delaunay;
+use graphics_test::strips;
+use graphics_test::validation;
+use graphics_test::XY;
+use crate::Vertex;
+
+#[allow(clippy::excessive_precision)]
+pub fn make_shapes() -> Vec<Vec<Vertex>>
+{
+    let paths = vec!(
+        vec!(XY::new(-1.0,-1.0), XY::new( 1.0,-1.0), XY::new( 1.0, 1.0), XY::new(-1.0, 1.0)),
+
+        vec!(XY::new(-0.6,-0.2), XY::new(-0.4,-0.2), XY::new(-0.2,-0.2), XY::new( 0.0,-0.2), XY::new( 0.2,-0.2),
+            XY::new( 0.4,-0.2), XY::new( 0.6,-0.2), XY::new( 0.7, 0.2), XY::new( 0.5, 0.2), XY::new( 0.3, 0.2),
+            XY::new( 0.1, 0.2), XY::new(-0.1, 0.2), XY::new(-0.3, 0.2), XY::new(-0.5, 0.2), XY::new(-0.7, 0.2)),
+
+        vec!(XY::new(-0.6, 0.0), XY::new(-0.5, 0.1), XY::new(-0.4, 0.1), XY::new(-0.3, 0.2), XY::new(-0.2, 0.3), 
+            XY::new(-0.1, 0.3), XY::new( 0.0, 0.4), XY::new( 0.1, 0.4), XY::new( 0.2, 0.5), XY::new( 0.3, 0.4), 
+            XY::new( 0.4, 0.2), XY::new( 0.5, 0.2), XY::new( 0.6, 0.1), XY::new( 0.5,-0.3), XY::new( 0.4,-0.3), 
+            XY::new( 0.3,-0.2), XY::new( 0.2,-0.4), XY::new( 0.1,-0.4), XY::new( 0.0,-0.3), XY::new(-0.1,-0.5), 
+            XY::new(-0.2,-0.7), XY::new(-0.3,-0.6), XY::new(-0.4,-0.7), XY::new(-0.5,-0.6)),
+            
+        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), 
+            XY::new(-0.05,-0.8), XY::new( 0.05,-0.8), 
+            XY::new( 0.1, 0.6), XY::new( 0.65, 0.6), 
+            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
+
+        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), 
+            XY::new(-0.05,-0.8), XY::new( 0.0, 0.0), XY::new( 0.05,-0.8), 
+            XY::new( 0.1, 0.6), XY::new( 0.65, 0.6), 
+            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
+
+        vec!(XY::new(-0.5, 0.0), XY::new(-0.2,-0.1), XY::new(-0.1,-0.8), XY::new( 0.1,-0.2), XY::new( 0.0, 0.0)),
+
+        vec!(XY::new(-0.65, 0.6), XY::new(-0.1, 0.6), XY::new( 0.0,-0.6),
+            XY::new(-0.4,-0.6), XY::new(-0.3,-0.8), XY::new( 0.1,-0.8),
+            XY::new( 0.2,-0.6), XY::new( 0.3, 0.6), XY::new( 0.65, 0.6), 
+            XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
+
+        vec!(XY::new(-0.5, 0.5), XY::new(-0.4, 0.6), XY::new( 0.5, 0.0), XY::new(-0.4,-0.6),
+            XY::new(-0.5,-0.5), XY::new( 0.0, 0.0)),
+
+        vec!(XY::new( 0.5, 0.5), XY::new( 0.4, 0.6), XY::new(-0.5, 0.0), XY::new( 0.4,-0.6),
+            XY::new( 0.5,-0.5), XY::new(-0.0, 0.0)),
+
+        vec!(XY::new(-0.5,-0.4), XY::new(-0.4,-0.5), XY::new(-0.2,-0.3), XY::new( 0.2,-0.3), XY::new( 0.4,-0.5), 
+            XY::new( 0.5,-0.4), XY::new( 0.1, 0.0), XY::new( 0.5, 0.4), XY::new( 0.4, 0.5), XY::new( 0.2, 0.3),
+            XY::new(-0.2, 0.3), XY::new(-0.4, 0.5), XY::new(-0.5, 0.4), XY::new(-0.1, 0.0)), 
+
+        vec!(XY::new(-0.5,-0.2), XY::new(-0.5,-0.1), XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.5, 0.2), 
+            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.5,-0.2)),
+
+        vec!(XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.5, 0.2), 
+            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.5,-0.2), 
+            XY::new(-0.5,-0.2), XY::new(-0.5,-0.1)),
+
+        vec!(XY::new(-0.5,-0.2), XY::new(-0.5,-0.1), XY::new(-0.5, 0.0), XY::new(-0.5, 0.1), XY::new(-0.3, 0.2), 
+            XY::new( 0.5, 0.2), XY::new( 0.5, 0.1), XY::new( 0.5, 0.0), XY::new( 0.5,-0.1), XY::new( 0.3,-0.2)), 
+
+        vec!(XY::new(-0.7,-0.5), XY::new(-0.8,-0.6),
+            XY::new(-0.4,-0.6), XY::new(-0.5,-0.5), XY::new(-0.5, 0.6), XY::new(-0.1, 0.6), 
+            XY::new(-0.1,-0.2), XY::new(-0.2,-0.3), XY::new( 0.2,-0.3), XY::new( 0.1, -0.2), 
+            XY::new( 0.1, 0.6), XY::new( 0.5, 0.6), XY::new( 0.5,-0.5), XY::new( 0.4,-0.6), XY::new(0.8, -0.6),
+            XY::new( 0.7,-0.5), XY::new( 0.7, 0.8), XY::new(-0.7, 0.8)),
+
+        vec!(XY::new((151.49479508399963-320.0)/320.0, (37.25356125831604-320.0)/320.0),
+            XY::new((143.24405981522432-320.0)/320.0, (42.898801179057045-320.0)/320.0),
+            XY::new((134.99955428620888-320.0)/320.0, (48.53977864627814-320.0)/320.0),
+            XY::new((126.74613561126898-320.0)/320.0, (54.18685458176333-320.0)/320.0),
+            XY::new((118.48821103252703-320.0)/320.0, (59.83701350406045-320.0)/320.0),
+            XY::new((110.24265909722453-320.0)/320.0, (65.47870693347795-320.0)/320.0),
+            XY::new((101.97458558996004-320.0)/320.0, (71.13580985950102-320.0)/320.0),
+            XY::new((93.72768214011012-320.0)/320.0, (76.77842800939834-320.0)/320.0),
+            XY::new((85.47457652172307-320.0)/320.0, (82.42528974829474-320.0)/320.0),
+            XY::new((77.22163051901498-320.0)/320.0, (88.07204227646343-320.0)/320.0),
+            XY::new((68.97166344726429-320.0)/320.0, (93.7167565887139-320.0)/320.0),
+            XY::new((60.72420496481209-320.0)/320.0, (99.35975449776015-320.0)/320.0),
+            XY::new((52.46207843301818-320.0)/320.0, (105.01278844056651-320.0)/320.0),
+            XY::new((44.20561546459794-320.0)/320.0, (110.66194731369615-320.0)/320.0),
+            XY::new((39.237521100687445-320.0)/320.0, (115.93976968318748-320.0)/320.0),
+            XY::new((48.528410255908966-320.0)/320.0, (119.62811261415482-320.0)/320.0),
+            XY::new((57.83031928908349-320.0)/320.0, (123.32083027054068-320.0)/320.0),
+            XY::new((67.11752508791687-320.0)/320.0, (127.00771096454991-320.0)/320.0),
+            XY::new((76.41461966428506-320.0)/320.0, (130.6985173541634-320.0)/320.0),
+            XY::new((85.7016077983335-320.0)/320.0, (134.3853116385344-320.0)/320.0),
+            XY::new((95.00132881860736-320.0)/320.0, (138.07716068678383-320.0)/320.0),
+            XY::new((104.29940029600584-320.0)/320.0, (141.7683548913792-320.0)/320.0),
+            XY::new((113.59307927683949-320.0)/320.0, (145.45780534105688-320.0)/320.0),
+            XY::new((122.88929328344784-320.0)/320.0, (149.14826215775065-320.0)/320.0),
+            XY::new((132.18410838172713-320.0)/320.0, (152.83816362892685-320.0)/320.0),
+            XY::new((132.66015625-320.0)/320.0, (156.833984375-320.0)/320.0),
+            XY::new((123.64010634063743-320.0)/320.0, (161.16539447999094-320.0)/320.0),
+            XY::new((114.63182582109948-320.0)/320.0, (165.4911529472938-320.0)/320.0),
+            XY::new((105.61387369197837-320.0)/320.0, (169.82155570236682-320.0)/320.0),
+            XY::new((96.59221583773251-320.0)/320.0, (174.15373793930667-320.0)/320.0),
+            XY::new((87.59105002467186-320.0)/320.0, (178.47607993864767-320.0)/320.0),
+            XY::new((78.57694321507006-320.0)/320.0, (182.804636178902-320.0)/320.0),
+            XY::new((69.56137649645098-320.0)/320.0, (187.13389346457552-320.0)/320.0),
+            XY::new((60.54597211981309-320.0)/320.0, (191.46307279395114-320.0)/320.0),
+            XY::new((51.53231927464367-320.0)/320.0, (195.79141104138398-320.0)/320.0),
+            XY::new((42.510309571167454-320.0)/320.0, (200.1237622356275-320.0)/320.0),
+            XY::new((38.71576214581728-320.0)/320.0, (203.7802967503667-320.0)/320.0),
+            XY::new((48.536488713885774-320.0)/320.0, (205.69795341750432-320.0)/320.0),
+            XY::new((58.353401970622144-320.0)/320.0, (207.61486547355344-320.0)/320.0),
+            XY::new((68.17180446481689-320.0)/320.0, (209.53206832744945-320.0)/320.0),
+            XY::new((77.98697050444207-320.0)/320.0, (211.44863921092656-320.0)/320.0),
+            XY::new((87.79986481183732-320.0)/320.0, (213.3647665017197-320.0)/320.0),
+            XY::new((97.60523153121176-320.0)/320.0, (215.27942390846147-320.0)/320.0),
+            XY::new((107.43585420178715-320.0)/320.0, (217.19901295064483-320.0)/320.0),
+            XY::new((117.23927618656307-320.0)/320.0, (219.11329061631113-320.0)/320.0),
+            XY::new((119.6603193921037-320.0)/320.0, (226.38347680238076-320.0)/320.0),
+            XY::new((118.40519514408516-320.0)/320.0, (236.29895836172727-320.0)/320.0),
+            XY::new((117.14928548736498-320.0)/320.0, (246.22064464981668-320.0)/320.0),
+            XY::new((115.89331520183623-320.0)/320.0, (256.1428099054938-320.0)/320.0),
+            XY::new((114.63776098084782-320.0)/320.0, (266.06168825130226-320.0)/320.0),
+            XY::new((113.3823506491608-320.0)/320.0, (275.9794298716297-320.0)/320.0),
+            XY::new((112.12614080851199-320.0)/320.0, (285.90348761275527-320.0)/320.0),
+            XY::new((110.87075745640323-320.0)/320.0, (295.8210160944145-320.0)/320.0),
+            XY::new((117.36467342311516-320.0)/320.0, (300.38176663336344-320.0)/320.0),
+            XY::new((127.08043038472533-320.0)/320.0, (302.69124984554946-320.0)/320.0),
+            XY::new((136.82198214903474-320.0)/320.0, (305.0068646091968-320.0)/320.0),
+            XY::new((146.5470655219906-320.0)/320.0, (307.31856475522727-320.0)/320.0),
+            XY::new((156.2753984630108-320.0)/320.0, (309.63103733956814-320.0)/320.0),
+            XY::new((166.0112459915108-320.0)/320.0, (311.94529617830995-320.0)/320.0),
+            XY::new((175.74134051223518-320.0)/320.0, (314.25818749881-320.0)/320.0),
+            XY::new((185.46250711433822-320.0)/320.0, (316.56895660914597-320.0)/320.0),
+            XY::new((195.19153725152137-320.0)/320.0, (318.8815949204436-320.0)/320.0),
+            XY::new((204.9115853912008-320.0)/320.0, (321.19209816676084-320.0)/320.0),
+            XY::new((214.64584860306786-320.0)/320.0, (323.5059804056473-320.0)/320.0),
+            XY::new((224.3770621312433-320.0)/320.0, (325.81913771972177-320.0)/320.0),
+            XY::new((231.08221468760166-320.0)/320.0, (328.6115250462899-320.0)/320.0),
+            XY::new((222.53925752235773-320.0)/320.0, (333.8026999744525-320.0)/320.0),
+            XY::new((213.998013121658-320.0)/320.0, (338.99283413181547-320.0)/320.0),
+            XY::new((205.4527522297576-320.0)/320.0, (344.18540893215686-320.0)/320.0),
+            XY::new((196.90229369455483-320.0)/320.0, (349.3811421090504-320.0)/320.0),
+            XY::new((188.35298773644718-320.0)/320.0, (354.5761749161302-320.0)/320.0),
+            XY::new((179.809910631373-320.0)/320.0, (359.7674227263906-320.0)/320.0),
+            XY::new((171.25848214607686-320.0)/320.0, (364.9637452987954-320.0)/320.0),
+            XY::new((162.72439449385274-320.0)/320.0, (370.14953061856795-320.0)/320.0),
+            XY::new((154.1675122132292-320.0)/320.0, (375.3491672197124-320.0)/320.0),
+            XY::new((145.62358783320633-320.0)/320.0, (380.5409298812574-320.0)/320.0),
+            XY::new((137.0795088929026-320.0)/320.0, (385.7327864622075-320.0)/320.0),
+            XY::new((128.53733253199607-320.0)/320.0, (390.9234869303182-320.0)/320.0),
+            XY::new((128.63895247876644-320.0)/320.0, (400.60194082558155-320.0)/320.0),
+            XY::new((129.3200240572296-320.0)/320.0, (410.57035211036055-320.0)/320.0),
+            XY::new((130.00158578154515-320.0)/320.0, (420.54593734806986-320.0)/320.0),
+            XY::new((130.68386690481566-320.0)/320.0, (430.53205197048374-320.0)/320.0),
+            XY::new((131.36575048890882-320.0)/320.0, (440.5123480649381-320.0)/320.0),
+            XY::new((132.04718094890632-320.0)/320.0, (450.4860120703561-320.0)/320.0),
+            XY::new((132.72882843017578-320.0)/320.0, (460.46285247802734-320.0)/320.0),
+            XY::new((133.4103733994575-320.0)/320.0, (470.43819248296904-320.0)/320.0),
+            XY::new((134.09214802986799-320.0)/320.0, (480.41689389170415-320.0)/320.0),
+            XY::new((134.77365913712083-320.0)/320.0, (490.3917382796776-320.0)/320.0),
+            XY::new((135.45518935550353-320.0)/320.0, (500.3668623850972-320.0)/320.0),
+            XY::new((136.13680260789624-320.0)/320.0, (510.3432018064814-320.0)/320.0),
+            XY::new((136.81853728135684-320.0)/320.0, (520.3213183907683-320.0)/320.0),
+            XY::new((137.50074147951455-320.0)/320.0, (530.3063071092583-320.0)/320.0),
+            XY::new((138.18219891376793-320.0)/320.0, (540.2803659196943-320.0)/320.0),
+            XY::new((138.86291046370752-320.0)/320.0, (550.2435076960828-320.0)/320.0),
+            XY::new((146.7712722722208-320.0)/320.0, (554.1727890158072-320.0)/320.0),
+            XY::new((156.47837973653805-320.0)/320.0, (556.5745475636795-320.0)/320.0),
+            XY::new((166.1754439281267-320.0)/320.0, (558.9738211780932-320.0)/320.0),
+            XY::new((175.88035914469765-320.0)/320.0, (561.375037314152-320.0)/320.0),
+            XY::new((185.5873788335448-320.0)/320.0, (563.7767741443822-320.0)/320.0),
+            XY::new((195.3061499393807-320.0)/320.0, (566.1814185417024-320.0)/320.0),
+            XY::new((205.00491508771142-320.0)/320.0, (568.5811130113925-320.0)/320.0),
+            XY::new((214.70652095051446-320.0)/320.0, (570.9815103382716-320.0)/320.0),
+            XY::new((224.42699172980792-320.0)/320.0, (573.3865752733545-320.0)/320.0),
+            XY::new((234.1214802294271-320.0)/320.0, (575.7852116031572-320.0)/320.0),
+            XY::new((243.8371512884753-320.0)/320.0, (578.1890889785918-320.0)/320.0),
+            XY::new((253.55417044495698-320.0)/320.0, (580.5932999039069-320.0)/320.0),
+            XY::new((263.2457120077579-320.0)/320.0, (582.991207094703-320.0)/320.0),
+            XY::new((272.9612408457324-320.0)/320.0, (585.3950492814183-320.0)/320.0),
+            XY::new((282.66034239530563-320.0)/320.0, (587.7948269844055-320.0)/320.0),
+            XY::new((292.3683239419479-320.0)/320.0, (586.2480004171375-320.0)/320.0),
+            XY::new((302.0482823237362-320.0)/320.0, (583.785052426505-320.0)/320.0),
+            XY::new((311.7466271368321-320.0)/320.0, (581.3174262314569-320.0)/320.0),
+            XY::new((321.4311059716565-320.0)/320.0, (578.8533280663833-320.0)/320.0),
+            XY::new((331.12736044824123-320.0)/320.0, (576.3862337321043-320.0)/320.0),
+            XY::new((340.8185914037663-320.0)/320.0, (573.9204175718228-320.0)/320.0),
+            XY::new((350.5053490380815-320.0)/320.0, (571.4557395938609-320.0)/320.0),
+            XY::new((360.19495760607606-320.0)/320.0, (568.9903362304067-320.0)/320.0),
+            XY::new((369.89738490753734-320.0)/320.0, (566.5216712957154-320.0)/320.0),
+            XY::new((379.58926456619884-320.0)/320.0, (564.0556900807896-320.0)/320.0),
+            XY::new((389.2769663099134-320.0)/320.0, (561.5907718856433-320.0)/320.0),
+            XY::new((398.96596419299067-320.0)/320.0, (559.1255239035586-320.0)/320.0),
+            XY::new((408.65479750931263-320.0)/320.0, (556.6603177934885-320.0)/320.0),
+            XY::new((418.35481561221604-320.0)/320.0, (554.1922658501462-320.0)/320.0),
+            XY::new((428.03891108685275-320.0)/320.0, (551.7282652264221-320.0)/320.0),
+            XY::new((437.7337135553862-320.0)/320.0, (549.2615403379787-320.0)/320.0),
+            XY::new((447.4228872297281-320.0)/320.0, (546.796247627939-320.0)/320.0),
+            XY::new((451.3728691500146-320.0)/320.0, (542.1882207462331-320.0)/320.0),
+            XY::new((445.66900289294426-320.0)/320.0, (533.9781102246925-320.0)/320.0),
+            XY::new((439.9586025842-320.0)/320.0, (525.7585946287727-320.0)/320.0),
+            XY::new((434.256012218073-320.0)/320.0, (517.5503206169233-320.0)/320.0),
+            XY::new((428.551600714447-320.0)/320.0, (509.3394252707949-320.0)/320.0),
+            XY::new((422.84682364948094-320.0)/320.0, (501.12800373788923-320.0)/320.0),
+            XY::new((414.99830865859985-320.0)/320.0, (499.83560848236084-320.0)/320.0),
+            XY::new((405.636616856631-320.0)/320.0, (503.38448511317256-320.0)/320.0),
+            XY::new((396.294921875-320.0)/320.0, (506.92578125-320.0)/320.0),
+            XY::new((386.94598599523306-320.0)/320.0, (510.46982230246067-320.0)/320.0),
+            XY::new((377.5818417440023-320.0)/320.0, (514.0196286199207-320.0)/320.0),
+            XY::new((368.2313457332639-320.0)/320.0, (517.5642610945797-320.0)/320.0),
+            XY::new((358.88610163668636-320.0)/320.0, (521.1069026475307-320.0)/320.0),
+            XY::new((349.5324653972639-320.0)/320.0, (524.6527255356777-320.0)/320.0),
+            XY::new((339.9760579795111-320.0)/320.0, (526.1288545874413-320.0)/320.0),
+            XY::new((330.0828419160389-320.0)/320.0, (524.6017138528114-320.0)/320.0),
+            XY::new((320.20512606039483-320.0)/320.0, (523.076965767712-320.0)/320.0),
+            XY::new((310.32920911049587-320.0)/320.0, (521.5524953660497-320.0)/320.0),
+            XY::new((300.42727717177695-320.0)/320.0, (520.0240092278582-320.0)/320.0),
+            XY::new((290.5495745504995-320.0)/320.0, (518.4992631856476-320.0)/320.0),
+            XY::new((280.6755625357655-320.0)/320.0, (516.9750868343799-320.0)/320.0),
+            XY::new((270.78223131948107-320.0)/320.0, (515.4479283244837-320.0)/320.0),
+            XY::new((260.89576650294475-320.0)/320.0, (513.9218297286425-320.0)/320.0),
+            XY::new((251.0253504235675-320.0)/320.0, (512.3982084546446-320.0)/320.0),
+            XY::new((241.1368497586932-320.0)/320.0, (510.8717956003352-320.0)/320.0),
+            XY::new((231.25896975956857-320.0)/320.0, (509.3470221776515-320.0)/320.0),
+            XY::new((221.38082339591347-320.0)/320.0, (507.82220763829537-320.0)/320.0),
+            XY::new((211.4969937756723-320.0)/320.0, (506.29651581772123-320.0)/320.0),
+            XY::new((201.60670001104518-320.0)/320.0, (504.7698261762016-320.0)/320.0),
+            XY::new((197.65330908447504-320.0)/320.0, (501.7600181866437-320.0)/320.0),
+            XY::new((205.65117263793945-320.0)/320.0, (495.7616205215454-320.0)/320.0),
+            XY::new((213.6440149502596-320.0)/320.0, (489.7669887873053-320.0)/320.0),
+            XY::new((221.65740885480773-320.0)/320.0, (483.7569433588942-320.0)/320.0),
+            XY::new((229.65527453401592-320.0)/320.0, (477.75854409948806-320.0)/320.0),
+            XY::new((237.65914262086153-320.0)/320.0, (471.75564303435385-320.0)/320.0),
+            XY::new((237.0732385488227-320.0)/320.0, (462.65760190505534-320.0)/320.0),
+            XY::new((234.75851440429688-320.0)/320.0, (452.9357604980469-320.0)/320.0),
+            XY::new((232.44086726858222-320.0)/320.0, (443.20164252804534-320.0)/320.0),
+            XY::new((230.12580379005522-320.0)/320.0, (433.4783759182319-320.0)/320.0),
+            XY::new((227.80867317027878-320.0)/320.0, (423.7464273151709-320.0)/320.0),
+            XY::new((225.49342685937881-320.0)/320.0, (414.022392809391-320.0)/320.0),
+            XY::new((223.17743436388264-320.0)/320.0, (404.2952243283071-320.0)/320.0),
+            XY::new((220.8591128132539-320.0)/320.0, (394.5582738156663-320.0)/320.0),
+            XY::new((220.94976359675638-320.0)/320.0, (386.5473018042976-320.0)/320.0),
+            XY::new((230.9078685163695-320.0)/320.0, (385.5120532730507-320.0)/320.0),
+            XY::new((240.853293600725-320.0)/320.0, (384.4781229424989-320.0)/320.0),
+            XY::new((250.80108275601015-320.0)/320.0, (383.44394684219697-320.0)/320.0),
+            XY::new((260.73664950649254-320.0)/320.0, (382.4110413879389-320.0)/320.0),
+            XY::new((270.68349742889404-320.0)/320.0, (381.3769631385803-320.0)/320.0),
+            XY::new((280.6434704780295-320.0)/320.0, (380.3415203958484-320.0)/320.0),
+            XY::new((290.5830474381801-320.0)/320.0, (379.30819803860504-320.0)/320.0),
+            XY::new((300.52618408203125-320.0)/320.0, (378.2745056152344-320.0)/320.0),
+            XY::new((310.4754348144925-320.0)/320.0, (377.2401775687904-320.0)/320.0),
+            XY::new((320.03229942172766-320.0)/320.0, (376.6669561713934-320.0)/320.0),
+            XY::new((320.8056374369189-320.0)/320.0, (386.65004691295326-320.0)/320.0),
+            XY::new((321.5779208282038-320.0)/320.0, (396.6195234186307-320.0)/320.0),
+            XY::new((322.3504435848008-320.0)/320.0, (406.59208991288324-320.0)/320.0),
+            XY::new((323.1216061189334-320.0)/320.0, (416.5470971716859-320.0)/320.0),
+            XY::new((323.8943871036172-320.0)/320.0, (426.52299715578556-320.0)/320.0),
+            XY::new((324.6661626431887-320.0)/320.0, (436.4859177575272-320.0)/320.0),
+            XY::new((325.43917311599944-320.0)/320.0, (446.46478022472-320.0)/320.0),
+            XY::new((334.6937026977539-320.0)/320.0, (447.6532325744629-320.0)/320.0),
+            XY::new((344.7007271235343-320.0)/320.0, (448.092137154541-320.0)/320.0),
+            XY::new((354.67739685299966-320.0)/320.0, (448.52971038828946-320.0)/320.0),
+            XY::new((364.6772343661578-320.0)/320.0, (448.96829975290166-320.0)/320.0),
+            XY::new((374.6735589080199-320.0)/320.0, (449.40673503982543-320.0)/320.0),
+            XY::new((384.6478386647359-320.0)/320.0, (449.8442034502077-320.0)/320.0),
+            XY::new((394.6517255432227-320.0)/320.0, (450.2829704185624-320.0)/320.0),
+            XY::new((404.6334469794151-320.0)/320.0, (450.7207652183954-320.0)/320.0),
+            XY::new((414.63110905350186-320.0)/320.0, (451.15925916901324-320.0)/320.0),
+            XY::new((424.61552891923066-320.0)/320.0, (451.5971723210189-320.0)/320.0),
+            XY::new((434.6139886458368-320.0)/320.0, (452.03570125639635-320.0)/320.0),
+            XY::new((444.59960412796636-320.0)/320.0, (452.4736668477178-320.0)/320.0),
+            XY::new((454.5866056623381-320.0)/320.0, (452.9116932308043-320.0)/320.0),
+            XY::new((464.5868865135417-320.0)/320.0, (453.3503020400676-320.0)/320.0),
+            XY::new((474.56021155757117-320.0)/320.0, (453.78772857708645-320.0)/320.0),
+            XY::new((484.5605423461566-320.0)/320.0, (454.2263395765858-320.0)/320.0),
+            XY::new((494.55012876796536-320.0)/320.0, (454.6644793319283-320.0)/320.0),
+            XY::new((494.87691896100296-320.0)/320.0, (446.8664635248715-320.0)/320.0),
+            XY::new((492.86049232522055-320.0)/320.0, (437.0723912939284-320.0)/320.0),
+            XY::new((490.8460185825825-320.0)/320.0, (427.287804543972-320.0)/320.0),
+            XY::new((488.8292851334554-320.0)/320.0, (417.49224207678344-320.0)/320.0),
+            XY::new((486.80992205807706-320.0)/320.0, (407.68390713923145-320.0)/320.0),
+            XY::new((484.7961384020746-320.0)/320.0, (397.90267223864794-320.0)/320.0),
+            XY::new((482.77965530753136-320.0)/320.0, (388.108325779438-320.0)/320.0),
+            XY::new((490.10236619280477-320.0)/320.0, (382.671909525714-320.0)/320.0),
+            XY::new((498.9056405065494-320.0)/320.0, (377.94962330443377-320.0)/320.0),
+            XY::new((507.71912332861393-320.0)/320.0, (373.2218609959091-320.0)/320.0),
+            XY::new((516.5365659133004-320.0)/320.0, (368.4919745763091-320.0)/320.0),
+            XY::new((525.3511385417223-320.0)/320.0, (363.76362766967213-320.0)/320.0),
+            XY::new((534.1631563428091-320.0)/320.0, (359.0366512333276-320.0)/320.0),
+            XY::new((542.9707644620648-320.0)/320.0, (354.31204025544866-320.0)/320.0),
+            XY::new((551.7894364258973-320.0)/320.0, (349.581494367565-320.0)/320.0),
+            XY::new((558.5002097189426-320.0)/320.0, (343.34600468724966-320.0)/320.0),
+            XY::new((560.1981483441778-320.0)/320.0, (333.4885276685236-320.0)/320.0),
+            XY::new((561.8946463842876-320.0)/320.0, (323.6394140467746-320.0)/320.0),
+            XY::new((563.5935670647959-320.0)/320.0, (313.7762356516014-320.0)/320.0),
+            XY::new((565.2888551860233-320.0)/320.0, (303.9341462811426-320.0)/320.0),
+            XY::new((566.986816410441-320.0)/320.0, (294.0765380616067-320.0)/320.0),
+            XY::new((568.6851993346936-320.0)/320.0, (284.216481640251-320.0)/320.0),
+            XY::new((570.3818083678998-320.0)/320.0, (274.3667236419151-320.0)/320.0),
+            XY::new((572.0803967439933-320.0)/320.0, (264.50547445848315-320.0)/320.0),
+            XY::new((573.777053134625-320.0)/320.0, (254.65544152398252-320.0)/320.0),
+            XY::new((575.4758850303479-320.0)/320.0, (244.79277857381385-320.0)/320.0),
+            XY::new((570.2217169562355-320.0)/320.0, (237.94931115303189-320.0)/320.0),
+            XY::new((561.8755188350187-320.0)/320.0, (232.45956586474495-320.0)/320.0),
+            XY::new((553.5152001932875-320.0)/320.0, (226.96053274745645-320.0)/320.0),
+            XY::new((545.1578931833646-320.0)/320.0, (221.46348054306873-320.0)/320.0),
+            XY::new((536.8047479146899-320.0)/320.0, (215.96916574067836-320.0)/320.0),
+            XY::new((528.4624964878458-320.0)/320.0, (210.48201640644402-320.0)/320.0),
+            XY::new((520.0922142186755-320.0)/320.0, (204.97642967324646-320.0)/320.0),
+            XY::new((511.7473912317637-320.0)/320.0, (199.48758888506381-320.0)/320.0),
+            XY::new((503.3959233940259-320.0)/320.0, (193.9943774195999-320.0)/320.0),
+            XY::new((495.0370349158911-320.0)/320.0, (188.496284998153-320.0)/320.0),
+            XY::new((486.68387523943966-320.0)/320.0, (183.00196071898972-320.0)/320.0),
+            XY::new((479.88097189285327-320.0)/320.0, (184.51403749675956-320.0)/320.0),
+            XY::new((474.65270513202995-320.0)/320.0, (193.02617428917438-320.0)/320.0),
+            XY::new((469.4189702923377-320.0)/320.0, (201.54721365627665-320.0)/320.0),
+            XY::new((464.1838229522109-320.0)/320.0, (210.07055271416903-320.0)/320.0),
+            XY::new((458.9467167357925-320.0)/320.0, (218.59708101693286-320.0)/320.0),
+            XY::new((453.7117740557296-320.0)/320.0, (227.12008686794434-320.0)/320.0),
+            XY::new((448.481169902263-320.0)/320.0, (235.63602916739-320.0)/320.0),
+            XY::new((443.244965611957-320.0)/320.0, (244.1610890449956-320.0)/320.0),
+            XY::new((438.01441222953144-320.0)/320.0, (252.67694868415128-320.0)/320.0),
+            XY::new((432.78131057333667-320.0)/320.0, (261.1969571657246-320.0)/320.0),
+            XY::new((427.54280935139104-320.0)/320.0, (269.7257566758344-320.0)/320.0),
+            XY::new((422.31108725536615-320.0)/320.0, (278.2435190966353-320.0)/320.0),
+            XY::new((412.71886610977344-320.0)/320.0, (277.28455780680633-320.0)/320.0),
+            XY::new((402.8373160569772-320.0)/320.0, (275.724313061628-320.0)/320.0),
+            XY::new((392.9589946918129-320.0)/320.0, (274.1645781092336-320.0)/320.0),
+            XY::new((383.0847912188092-320.0)/320.0, (272.6054933503383-320.0)/320.0),
+            XY::new((373.20876444700116-320.0)/320.0, (271.0461207021581-320.0)/320.0),
+            XY::new((363.32000667916145-320.0)/320.0, (269.4847378967097-320.0)/320.0),
+            XY::new((353.4500020095977-320.0)/320.0, (267.9263161067786-320.0)/320.0),
+            XY::new((343.572348442204-320.0)/320.0, (266.36668659613747-320.0)/320.0),
+            XY::new((333.7033881291263-320.0)/320.0, (264.8084297045989-320.0)/320.0),
+            XY::new((323.80676251649857-320.0)/320.0, (263.2458046078682-320.0)/320.0),
+            XY::new((313.9384826645057-320.0)/320.0, (261.68765515755354-320.0)/320.0),
+            XY::new((304.05744046642394-320.0)/320.0, (260.1274905999617-320.0)/320.0),
+            XY::new((294.1799965605169-320.0)/320.0, (258.5678941937658-320.0)/320.0),
+            XY::new((284.30241054181533-320.0)/320.0, (257.0082753487077-320.0)/320.0),
+            XY::new((281.67261315410724-320.0)/320.0, (251.59031652474368-320.0)/320.0),
+            XY::new((285.89248548581963-320.0)/320.0, (242.53980086593947-320.0)/320.0),
+            XY::new((290.12300952424994-320.0)/320.0, (233.46644009930606-320.0)/320.0),
+            XY::new((294.34824738636235-320.0)/320.0, (224.4044167897755-320.0)/320.0),
+            XY::new((298.5695799398818-320.0)/320.0, (215.35076933946402-320.0)/320.0),
+            XY::new((302.8014110590084-320.0)/320.0, (206.2746052287057-320.0)/320.0),
+            XY::new((307.02833013172494-320.0)/320.0, (197.20897616485308-320.0)/320.0),
+            XY::new((311.25167990284535-320.0)/320.0, (188.1510023136343-320.0)/320.0),
+            XY::new((315.477091912122-320.0)/320.0, (179.0886055042647-320.0)/320.0),
+            XY::new((322.5185452313599-320.0)/320.0, (173.31612993389717-320.0)/320.0),
+            XY::new((332.1281548142433-320.0)/320.0, (170.57052719593048-320.0)/320.0),
+            XY::new((341.7477750694525-320.0)/320.0, (167.82206426587072-320.0)/320.0),
+            XY::new((351.35394579559033-320.0)/320.0, (165.07744405840276-320.0)/320.0),
+            XY::new((360.9804516411077-320.0)/320.0, (162.32701381682637-320.0)/320.0),
+            XY::new((370.5806767926606-320.0)/320.0, (159.58409234495412-320.0)/320.0),
+            XY::new((380.20394744937335-320.0)/320.0, (156.8345864430362-320.0)/320.0),
+            XY::new((389.8278150400147-320.0)/320.0, (154.08490998856723-320.0)/320.0),
+            XY::new((399.4374666986987-320.0)/320.0, (151.33929522894323-320.0)/320.0),
+            XY::new((409.0508004155836-320.0)/320.0, (148.5926284526904-320.0)/320.0),
+            XY::new((418.6635300848866-320.0)/320.0, (145.84613426146097-320.0)/320.0),
+            XY::new((428.27505047451996-320.0)/320.0, (143.09998557870858-320.0)/320.0),
+            XY::new((428.38405254576355-320.0)/320.0, (139.61956271342933-320.0)/320.0),
+            XY::new((419.2966176653699-320.0)/320.0, (135.4253619994015-320.0)/320.0),
+            XY::new((410.2222808239476-320.0)/320.0, (131.23720653412965-320.0)/320.0),
+            XY::new((401.1354800985082-320.0)/320.0, (127.04329850700378-320.0)/320.0),
+            XY::new((392.05854604995534-320.0)/320.0, (122.85394433074862-320.0)/320.0),
+            XY::new((382.9792313940204-320.0)/320.0, (118.66349141262481-320.0)/320.0),
+            XY::new((373.89506288138546-320.0)/320.0, (114.47079825294713-320.0)/320.0),
+            XY::new((364.81554594290174-320.0)/320.0, (110.28025197364695-320.0)/320.0),
+            XY::new((355.73933972466307-320.0)/320.0, (106.09123371907526-320.0)/320.0),
+            XY::new((346.6587236645955-320.0)/320.0, (101.90018015289024-320.0)/320.0),
+            XY::new((337.5819794803738-320.0)/320.0, (97.71091360632636-320.0)/320.0),
+            XY::new((328.4924894100841-320.0)/320.0, (93.51576434311573-320.0)/320.0),
+            XY::new((319.4237535852153-320.0)/320.0, (89.33019396240707-320.0)/320.0),
+            XY::new((310.33786180836614-320.0)/320.0, (85.13670545001514-320.0)/320.0),
+            XY::new((300.45047208829783-320.0)/320.0, (85.37927180842962-320.0)/320.0),
+            XY::new((290.471361745982-320.0)/320.0, (86.0731834547496-320.0)/320.0),
+            XY::new((280.49739099598446-320.0)/320.0, (86.76673771219976-320.0)/320.0),
+            XY::new((270.51559717184864-320.0)/320.0, (87.46083595824894-320.0)/320.0),
+            XY::new((260.5528499498141-320.0)/320.0, (88.15360977170167-320.0)/320.0),
+            XY::new((250.5789058727678-320.0)/320.0, (88.84716217441019-320.0)/320.0),
+            XY::new((240.60059378752703-320.0)/320.0, (89.54101831278786-320.0)/320.0),
+            XY::new((230.6140164150761-320.0)/320.0, (90.23544918968014-320.0)/320.0),
+            XY::new((220.6393109389851-320.0)/320.0, (90.92905453735534-320.0)/320.0),
+            XY::new((210.6638540778108-320.0)/320.0, (91.62271213366216-320.0)/320.0),
+            XY::new((200.68738048863088-320.0)/320.0, (92.31644042959852-320.0)/320.0),
+            XY::new((190.70807765610516-320.0)/320.0, (93.01036546099931-320.0)/320.0),
+            XY::new((180.73998809765908-320.0)/320.0, (93.70351076142106-320.0)/320.0),
+            XY::new((170.76098135384382-320.0)/320.0, (94.39741520387179-320.0)/320.0),
+            XY::new((160.78148931008764-320.0)/320.0, (95.0913533923449-320.0)/320.0),
+            XY::new((157.57587109910673-320.0)/320.0, (87.59293196402723-320.0)/320.0),
+            XY::new((156.37839531895588-320.0)/320.0, (77.67098978563445-320.0)/320.0),
+            XY::new((155.17937348107807-320.0)/320.0, (67.7362374146469-320.0)/320.0),
+            XY::new((153.9827174951788-320.0)/320.0, (57.8210878171958-320.0)/320.0),
+            XY::new((152.78390789031982-320.0)/320.0, (47.88809394836426-320.0)/320.0),
+            XY::new((151.58598042093217-320.0)/320.0, (37.96240920200944-320.0)/320.0)),
+
+        vec!(XY::new((131.26511375309482-320.0)/320.0, (315.0509064777915-320.0)/320.0),
+            XY::new((140.92901787525858-320.0)/320.0, (317.57127652786323-320.0)/320.0),
+            XY::new((149.6569206685644-320.0)/320.0, (322.3559301834826-320.0)/320.0),
+            XY::new((156.5513058758124-320.0)/320.0, (329.5658255668347-320.0)/320.0),
+            XY::new((162.9627577706694-320.0)/320.0, (337.2363098888437-320.0)/320.0),
+            XY::new((170.5306071453191-320.0)/320.0, (343.7344796842716-320.0)/320.0),
+            XY::new((179.2024018301652-320.0)/320.0, (348.7043988451373-320.0)/320.0),
+            XY::new((188.5643308689259-320.0)/320.0, (352.1849040246289-320.0)/320.0),
+            XY::new((198.25779532841642-320.0)/320.0, (354.624149696572-320.0)/320.0),
+            XY::new((208.1059813770125-320.0)/320.0, (356.3279409400844-320.0)/320.0),
+            XY::new((218.04702812788787-320.0)/320.0, (357.46799386085235-320.0)/320.0),
+            XY::new((228.02886006431888-320.0)/320.0, (358.1366280375246-320.0)/320.0),
+            XY::new((238.02431083459174-320.0)/320.0, (358.3771046745486-320.0)/320.0),
+            XY::new((248.02387966827865-320.0)/320.0, (358.1792819833367-320.0)/320.0),
+            XY::new((257.9873912388147-320.0)/320.0, (357.42068505432877-320.0)/320.0),
+            XY::new((267.87686670078534-320.0)/320.0, (357.6107422371159-320.0)/320.0),
+            XY::new((277.2440465728077-320.0)/320.0, (361.07897707828346-320.0)/320.0),
+            XY::new((286.2429173938034-320.0)/320.0, (365.4333766419324-320.0)/320.0),
+            XY::new((295.05376953125-320.0)/320.0, (370.1661328125-320.0)/320.0),
+            XY::new((303.75345794772505-320.0)/320.0, (375.08855516323695-320.0)/320.0),
+            XY::new((312.4015631908178-320.0)/320.0, (380.1050769984722-320.0)/320.0),
+            XY::new((321.0423497447869-320.0)/320.0, (385.14467850018957-320.0)/320.0),
+            XY::new((329.71655453010294-320.0)/320.0, (390.13356017420114-320.0)/320.0),
+            XY::new((338.4587140099776-320.0)/320.0, (394.9573447813958-320.0)/320.0),
+            XY::new((347.4112398244607-320.0)/320.0, (399.4285239807649-320.0)/320.0),
+            XY::new((356.8342694118712-320.0)/320.0, (402.72911629961806-320.0)/320.0),
+            XY::new((365.8541223530751-320.0)/320.0, (406.3194980073069-320.0)/320.0),
+            XY::new((370.73773147592203-320.0)/320.0, (414.96126267565995-320.0)/320.0),
+            XY::new((372.89458740234375-320.0)/320.0, (424.70194580078123-320.0)/320.0),
+            XY::new((373.45156429083204-320.0)/320.0, (434.672863501556-320.0)/320.0),
+            XY::new((372.570822738536-320.0)/320.0, (444.6233630668733-320.0)/320.0),
+            XY::new((368.9945853883517-320.0)/320.0, (453.90372824593913-320.0)/320.0),
+            XY::new((363.37497071268035-320.0)/320.0, (462.1624630321189-320.0)/320.0),
+            XY::new((357.0424622368999-320.0)/320.0, (469.8835512460396-320.0)/320.0),
+            XY::new((350.1725698737544-320.0)/320.0, (477.16001456528437-320.0)/320.0),
+            XY::new((342.7650427394011-320.0)/320.0, (483.88145981320645-320.0)/320.0),
+            XY::new((334.64046265250073-320.0)/320.0, (489.6963320222869-320.0)/320.0),
+            XY::new((325.323708929997-320.0)/320.0, (493.03481383670123-320.0)/320.0),
+            XY::new((315.39153368569913-320.0)/320.0, (491.9518843227625-320.0)/320.0),
+            XY::new((305.44356719825146-320.0)/320.0, (490.8595072931715-320.0)/320.0),
+            XY::new((295.50917682919965-320.0)/320.0, (489.77115102462585-320.0)/320.0),
+            XY::new((285.56719566466705-320.0)/320.0, (488.6633417580009-320.0)/320.0),
+            XY::new((275.6362328691625-320.0)/320.0, (487.5251197022956-320.0)/320.0),
+            XY::new((265.69647811613396-320.0)/320.0, (486.3413168974128-320.0)/320.0),
+            XY::new((255.78587077888758-320.0)/320.0, (485.1000861012332-320.0)/320.0),
+            XY::new((245.8776756726287-320.0)/320.0, (483.7732740030717-320.0)/320.0),
+            XY::new((235.97173461563887-320.0)/320.0, (482.3159566575289-320.0)/320.0),
+            XY::new((226.1279901677264-320.0)/320.0, (480.63737284536825-320.0)/320.0),
+            XY::new((216.3292834209712-320.0)/320.0, (478.60792654444435-320.0)/320.0),
+            XY::new((206.47337188696721-320.0)/320.0, (479.8895512817311-320.0)/320.0),
+            XY::new((197.1808344475925-320.0)/320.0, (483.53415978446606-320.0)/320.0),
+            XY::new((188.31954863039545-320.0)/320.0, (488.1622994491575-320.0)/320.0),
+            XY::new((179.71268812102497-320.0)/320.0, (493.27616276830213-320.0)/320.0),
+            XY::new((171.25883253299315-320.0)/320.0, (498.60666806579434-320.0)/320.0),
+            XY::new((162.831884432293-320.0)/320.0, (503.980950287527-320.0)/320.0),
+            XY::new((154.29948086307238-320.0)/320.0, (509.1980421414183-320.0)/320.0),
+            XY::new((145.45352427830687-320.0)/320.0, (513.8654340807465-320.0)/320.0),
+            XY::new((135.87257038543814-320.0)/320.0, (516.3928724640084-320.0)/320.0),
+            XY::new((127.21195472654755-320.0)/320.0, (511.9264516031072-320.0)/320.0),
+            XY::new((121.85527539119241-320.0)/320.0, (503.53660505544985-320.0)/320.0),
+            XY::new((118.37768768310548-320.0)/320.0, (494.1724325561524-320.0)/320.0),
+            XY::new((115.96984445497976-320.0)/320.0, (484.4687719291368-320.0)/320.0),
+            XY::new((114.305765977454-320.0)/320.0, (474.59994042678545-320.0)/320.0),
+            XY::new((113.29845423042774-320.0)/320.0, (464.6636698299646-320.0)/320.0),
+            XY::new((109.57576548576355-320.0)/320.0, (455.7138435306307-320.0)/320.0),
+            XY::new((100.52194824934007-320.0)/320.0, (451.7599533522839-320.0)/320.0),
+            XY::new((90.56022923231126-320.0)/320.0, (451.00694374792863-320.0)/320.0),
+            XY::new((80.58849695861339-320.0)/320.0, (451.6763456336047-320.0)/320.0),
+            XY::new((74.31310149490834-320.0)/320.0, (458.45836153804953-320.0)/320.0),
+            XY::new((69.84388030827046-320.0)/320.0, (467.40082951055473-320.0)/320.0),
+            XY::new((66.7062226986885-320.0)/320.0, (476.8755230092781-320.0)/320.0),
+            XY::new((67.73630612373353-320.0)/320.0, (486.57628110670487-320.0)/320.0),
+            XY::new((76.44554736375808-320.0)/320.0, (490.6982918056299-320.0)/320.0),
+            XY::new((85.88001259932179-320.0)/320.0, (493.6172311852826-320.0)/320.0),
+            XY::new((92.24719543538987-320.0)/320.0, (501.2023964199424-320.0)/320.0),
+            XY::new((95.66287288354609-320.0)/320.0, (510.57652142530833-320.0)/320.0),
+            XY::new((97.99636016845703-320.0)/320.0, (520.2985559082032-320.0)/320.0),
+            XY::new((100.67944702866487-320.0)/320.0, (529.9319808037206-320.0)/320.0),
+            XY::new((105.21198466874658-320.0)/320.0, (538.8087948579621-320.0)/320.0),
+            XY::new((111.06853412628173-320.0)/320.0, (546.918419585228-320.0)/320.0),
+            XY::new((118.45288740046323-320.0)/320.0, (553.6025316155608-320.0)/320.0),
+            XY::new((127.63609568119048-320.0)/320.0, (557.4013230150938-320.0)/320.0),
+            XY::new((137.56669964355416-320.0)/320.0, (557.3369272299382-320.0)/320.0),
+            XY::new((147.15548113159835-320.0)/320.0, (554.5018098327308-320.0)/320.0),
+            XY::new((156.37618358232083-320.0)/320.0, (550.6551283065905-320.0)/320.0),
+            XY::new((165.34254065811635-320.0)/320.0, (546.2534758389927-320.0)/320.0),
+            XY::new((174.11251468122006-320.0)/320.0, (541.415652560722-320.0)/320.0),
+            XY::new((182.6101090556383-320.0)/320.0, (536.1489641786553-320.0)/320.0),
+            XY::new((190.93005824527353-320.0)/320.0, (530.6449471768132-320.0)/320.0),
+            XY::new((200.4141154417592-320.0)/320.0, (527.5471889929387-320.0)/320.0),
+            XY::new((210.23217933478549-320.0)/320.0, (525.6344396364078-320.0)/320.0),
+            XY::new((220.14704573958284-320.0)/320.0, (524.3858774587053-320.0)/320.0),
+            XY::new((230.12767009589254-320.0)/320.0, (523.6480277893967-320.0)/320.0),
+            XY::new((240.11328281350097-320.0)/320.0, (523.3947551551641-320.0)/320.0),
+            XY::new((250.10699984567282-320.0)/320.0, (523.6824599736678-320.0)/320.0),
+            XY::new((260.06803085754905-320.0)/320.0, (524.7024035400182-320.0)/320.0),
+            XY::new((269.7693387653399-320.0)/320.0, (527.0158662522678-320.0)/320.0),
+            XY::new((278.81507338557026-320.0)/320.0, (531.2641962611634-320.0)/320.0),
+            XY::new((287.17718462266913-320.0)/320.0, (536.7254030446081-320.0)/320.0),
+            XY::new((295.1445602416992-320.0)/320.0, (542.7566293334961-320.0)/320.0),
+            XY::new((302.9990124896773-320.0)/320.0, (548.9553290217549-320.0)/320.0),
+            XY::new((310.9421083081105-320.0)/320.0, (555.0335643610316-320.0)/320.0),
+            XY::new((319.1302134237718-320.0)/320.0, (560.7789433192369-320.0)/320.0),
+            XY::new((327.64453691309535-320.0)/320.0, (566.0010686586859-320.0)/320.0),
+            XY::new((336.5732254316577-320.0)/320.0, (570.4872400928521-320.0)/320.0),
+            XY::new((346.3526702884678-320.0)/320.0, (572.441955945678-320.0)/320.0),
+            XY::new((356.29155699589126-320.0)/320.0, (571.577642778252-320.0)/320.0),
+            XY::new((365.852380233407-320.0)/320.0, (568.7312194561958-320.0)/320.0),
+            XY::new((374.92011781899913-320.0)/320.0, (564.5087451193069-320.0)/320.0),
+            XY::new((383.43425271407705-320.0)/320.0, (559.291268630512-320.0)/320.0),
+            XY::new((391.4077214155755-320.0)/320.0, (553.259007285678-320.0)/320.0),
+            XY::new((398.7695502073702-320.0)/320.0, (546.4957959784427-320.0)/320.0),
+            XY::new((405.32889190673825-320.0)/320.0, (538.9656799316406-320.0)/320.0),
+            XY::new((410.42196675115144-320.0)/320.0, (530.3906495790695-320.0)/320.0),
+            XY::new((412.965682430357-320.0)/320.0, (520.8330011479383-320.0)/320.0),
+            XY::new((420.7315711813757-320.0)/320.0, (514.7140486598772-320.0)/320.0),
+            XY::new((430.14056465703914-320.0)/320.0, (511.3840665727282-320.0)/320.0),
+            XY::new((439.9409752653597-320.0)/320.0, (509.3918884468183-320.0)/320.0),
+            XY::new((449.87370581411176-320.0)/320.0, (508.24490410890314-320.0)/320.0),
+            XY::new((459.85512891352755-320.0)/320.0, (507.8015982021496-320.0)/320.0),
+            XY::new((469.68678713396423-320.0)/320.0, (509.2021968174993-320.0)/320.0),
+            XY::new((478.36659194336505-320.0)/320.0, (514.084734425008-320.0)/320.0),
+            XY::new((485.740019789543-320.0)/320.0, (520.8379768570978-320.0)/320.0),
+            XY::new((491.77933544889095-320.0)/320.0, (528.7942465000599-320.0)/320.0),
+            XY::new((496.24203112948715-320.0)/320.0, (537.7196948016096-320.0)/320.0),
+            XY::new((498.51757092952727-320.0)/320.0, (547.415043592453-320.0)/320.0),
+            XY::new((505.2062269751762-320.0)/320.0, (554.4500231084298-320.0)/320.0),
+            XY::new((514.5247788898263-320.0)/320.0, (558.0305135305948-320.0)/320.0),
+            XY::new((524.2687777480301-320.0)/320.0, (560.2918849193686-320.0)/320.0),
+            XY::new((534.1401196239598-320.0)/320.0, (561.8686397238984-320.0)/320.0),
+            XY::new((544.0771610780042-320.0)/320.0, (563.0178061021828-320.0)/320.0),
+            XY::new((554.0403335946274-320.0)/320.0, (563.8671959267999-320.0)/320.0),
+            XY::new((564.0270525569703-320.0)/320.0, (564.4933345301918-320.0)/320.0),
+            XY::new((574.0091507158162-320.0)/320.0, (564.9437009916293-320.0)/320.0),
+            XY::new((584.0063990402222-320.0)/320.0, (565.2528347015381-320.0)/320.0),
+            XY::new((594.0000882771358-320.0)/320.0, (565.4441697577624-320.0)/320.0),
+            XY::new((599.16959073678-320.0)/320.0, (560.3432527542114-320.0)/320.0),
+            XY::new((598.2018319914956-320.0)/320.0, (550.4235823059082-320.0)/320.0),
+            XY::new((593.6742921685334-320.0)/320.0, (541.6196113586426-320.0)/320.0),
+            XY::new((585.2883668076992-320.0)/320.0, (536.3433798593283-320.0)/320.0),
+            XY::new((575.8616346140205-320.0)/320.0, (533.0017957002669-320.0)/320.0),
+            XY::new((566.5862304874812-320.0)/320.0, (529.2791989989311-320.0)/320.0),
+            XY::new((558.503248090744-320.0)/320.0, (523.4587436628342-320.0)/320.0),
+            XY::new((552.7666707029194-320.0)/320.0, (515.3361743126624-320.0)/320.0),
+            XY::new((548.8571369932953-320.0)/320.0, (506.1293565987385-320.0)/320.0),
+            XY::new((545.9588496160508-320.0)/320.0, (496.5545475757122-320.0)/320.0),
+            XY::new((543.074934540391-320.0)/320.0, (486.9880035085976-320.0)/320.0),
+            XY::new((536.4788210920524-320.0)/320.0, (480.15575611863284-320.0)/320.0),
+            XY::new((526.6125309644464-320.0)/320.0, (478.54778237007383-320.0)/320.0),
+            XY::new((516.6706010373018-320.0)/320.0, (477.4689690643409-320.0)/320.0),
+            XY::new((506.71719391341185-320.0)/320.0, (476.51043884568674-320.0)/320.0),
+            XY::new((496.762486451248-320.0)/320.0, (475.54157392145544-320.0)/320.0),
+            XY::new((486.81411531679333-320.0)/320.0, (474.4443866744638-320.0)/320.0),
+            XY::new((476.9315398659303-320.0)/320.0, (472.978328003778-320.0)/320.0),
+            XY::new((467.52138030319475-320.0)/320.0, (469.7593194087967-320.0)/320.0),
+            XY::new((459.5387318042945-320.0)/320.0, (463.76475892316546-320.0)/320.0),
+            XY::new((453.01821097267486-320.0)/320.0, (456.2096213033068-320.0)/320.0),
+            XY::new((447.88374722003937-320.0)/320.0, (447.6474652290344-320.0)/320.0),
+            XY::new((444.1610349077091-320.0)/320.0, (438.3718424207764-320.0)/320.0),
+            XY::new((441.95188188422253-320.0)/320.0, (428.6360114337091-320.0)/320.0),
+            XY::new((442.31202074657546-320.0)/320.0, (418.7167274933134-320.0)/320.0),
+            XY::new((446.34968134053054-320.0)/320.0, (409.6143252386898-320.0)/320.0),
+            XY::new((451.93989202345415-320.0)/320.0, (401.3310153960671-320.0)/320.0),
+            XY::new((458.02217634963176-320.0)/320.0, (393.37420519005957-320.0)/320.0),
+            XY::new((463.9519013234868-320.0)/320.0, (385.34032551706423-320.0)/320.0),
+            XY::new((469.0467738823801-320.0)/320.0, (376.7327038992867-320.0)/320.0),
+            XY::new((471.84501306869106-320.0)/320.0, (367.20328329674476-320.0)/320.0),
+            XY::new((470.04598614378835-320.0)/320.0, (357.49477307838384-320.0)/320.0),
+            XY::new((464.00614221583965-320.0)/320.0, (349.56931130150156-320.0)/320.0),
+            XY::new((455.66467627967796-320.0)/320.0, (344.17251597795814-320.0)/320.0),
+            XY::new((445.9189873267524-320.0)/320.0, (342.1412756386027-320.0)/320.0),
+            XY::new((435.9342150453362-320.0)/320.0, (342.58646968183575-320.0)/320.0),
+            XY::new((426.010944378165-320.0)/320.0, (343.7712812782888-320.0)/320.0),
+            XY::new((416.09420510962605-320.0)/320.0, (343.15175838857886-320.0)/320.0),
+            XY::new((408.92785163884054-320.0)/320.0, (336.5971363054402-320.0)/320.0),
+            XY::new((407.7117356702837-320.0)/320.0, (326.79808338605096-320.0)/320.0),
+            XY::new((409.71842452469633-320.0)/320.0, (317.0169943341031-320.0)/320.0),
+            XY::new((412.5985939886328-320.0)/320.0, (307.43641638433564-320.0)/320.0),
+            XY::new((415.41891738698814-320.0)/320.0, (297.84139102498074-320.0)/320.0),
+            XY::new((417.62791387691163-320.0)/320.0, (288.0858195432089-320.0)/320.0),
+            XY::new((418.84495545444776-320.0)/320.0, (278.16242144009567-320.0)/320.0),
+            XY::new((423.0929623899632-320.0)/320.0, (269.2849792240327-320.0)/320.0),
+            XY::new((431.32519964295204-320.0)/320.0, (263.7203077811422-320.0)/320.0),
+            XY::new((440.84129056768984-320.0)/320.0, (260.726655537586-320.0)/320.0),
+            XY::new((450.69594880564136-320.0)/320.0, (259.04438837219027-320.0)/320.0),
+            XY::new((460.6361927542347-320.0)/320.0, (257.99104820762295-320.0)/320.0),
+            XY::new((470.6021484867047-320.0)/320.0, (257.12732244635777-320.0)/320.0),
+            XY::new((480.53218389718126-320.0)/320.0, (256.0261533652357-320.0)/320.0),
+            XY::new((490.30019528607835-320.0)/320.0, (253.9407653801795-320.0)/320.0),
+            XY::new((498.650291725247-320.0)/320.0, (248.64187480333612-320.0)/320.0),
+            XY::new((502.4978423199323-320.0)/320.0, (239.5688494147062-320.0)/320.0),
+            XY::new((502.11333860414703-320.0)/320.0, (229.61791014267786-320.0)/320.0),
+            XY::new((499.5180777364224-320.0)/320.0, (219.96341605685652-320.0)/320.0),
+            XY::new((495.7170626532122-320.0)/320.0, (210.72399570481545-320.0)/320.0),
+            XY::new((491.7550762656052-320.0)/320.0, (201.55439076134792-320.0)/320.0),
+            XY::new((489.7153452762217-320.0)/320.0, (191.7930917863478-320.0)/320.0),
+            XY::new((489.76656959670595-320.0)/320.0, (181.80814763021337-320.0)/320.0),
+            XY::new((491.84305707089607-320.0)/320.0, (172.04917762546103-320.0)/320.0),
+            XY::new((495.7369261628389-320.0)/320.0, (162.8373841627501-320.0)/320.0),
+            XY::new((501.1083874481916-320.0)/320.0, (154.4252059247531-320.0)/320.0),
+            XY::new((507.522669982848-320.0)/320.0, (146.74471078621036-320.0)/320.0),
+            XY::new((514.1249019894063-320.0)/320.0, (139.24962290174315-320.0)/320.0),
+            XY::new((520.6140324543348-320.0)/320.0, (131.63727751002983-320.0)/320.0),
+            XY::new((526.6399690127496-320.0)/320.0, (123.66254814425295-320.0)/320.0),
+            XY::new((531.4986972607132-320.0)/320.0, (114.94159537929968-320.0)/320.0),
+            XY::new((533.1311626775841-320.0)/320.0, (105.19459291731008-320.0)/320.0),
+            XY::new((528.4706996488292-320.0)/320.0, (96.56972821961156-320.0)/320.0),
+            XY::new((519.7238307134315-320.0)/320.0, (92.05718170751047-320.0)/320.0),
+            XY::new((509.7595252533772-320.0)/320.0, (92.13344236785036-320.0)/320.0),
+            XY::new((500.05634454237673-320.0)/320.0, (94.46504726722037-320.0)/320.0),
+            XY::new((490.67890225094015-320.0)/320.0, (97.93989804750235-320.0)/320.0),
+            XY::new((481.5775609001671-320.0)/320.0, (102.09712228326374-320.0)/320.0),
+            XY::new((472.7086939373033-320.0)/320.0, (106.70240368993095-320.0)/320.0),
+            XY::new((464.0026614607312-320.0)/320.0, (111.63445093841293-320.0)/320.0),
+            XY::new((455.4357347146826-320.0)/320.0, (116.77358653333721-320.0)/320.0),
+            XY::new((447.2575969526638-320.0)/320.0, (122.27091407464818-320.0)/320.0),
+            XY::new((447.0157528719958-320.0)/320.0, (132.20725107496605-320.0)/320.0),
+            XY::new((448.62128616900145-320.0)/320.0, (142.07341942079861-320.0)/320.0),
+            XY::new((450.8960936307907-320.0)/320.0, (151.80571200370787-320.0)/320.0),
+            XY::new((453.70076431977566-320.0)/320.0, (161.40082159457728-320.0)/320.0),
+            XY::new((457.182547818359-320.0)/320.0, (170.77149359385513-320.0)/320.0),
+            XY::new((458.1073041110909-320.0)/320.0, (180.32009514411183-320.0)/320.0),
+            XY::new((453.06201330444003-320.0)/320.0, (188.91059878460567-320.0)/320.0),
+            XY::new((446.4875857922093-320.0)/320.0, (196.44549758502777-320.0)/320.0),
+            XY::new((439.23677296399916-320.0)/320.0, (203.3236427218144-320.0)/320.0),
+            XY::new((431.54300793093284-320.0)/320.0, (209.6973470719182-320.0)/320.0),
+            XY::new((423.463092779568-320.0)/320.0, (215.6002130398041-320.0)/320.0),
+            XY::new((414.9858129882813-320.0)/320.0, (220.9065283203125-320.0)/320.0),
+            XY::new((405.8928288290522-320.0)/320.0, (225.02683946062112-320.0)/320.0),
+            XY::new((397.7930834549293-320.0)/320.0, (230.24810728771612-320.0)/320.0),
+            XY::new((393.3491494068876-320.0)/320.0, (239.18173079879955-320.0)/320.0),
+            XY::new((390.11150501810476-320.0)/320.0, (248.65545964533692-320.0)/320.0),
+            XY::new((387.200691333607-320.0)/320.0, (258.21514254269334-320.0)/320.0),
+            XY::new((384.1545009821054-320.0)/320.0, (267.73508038584635-320.0)/320.0),
+            XY::new((380.5223486170173-320.0)/320.0, (277.0520439986884-320.0)/320.0),
+            XY::new((375.5695706080878-320.0)/320.0, (285.70890384498284-320.0)/320.0),
+            XY::new((368.278752889171-320.0)/320.0, (292.50609403600794-320.0)/320.0),
+            XY::new((359.69889982879164-320.0)/320.0, (297.6282885020972-320.0)/320.0),
+            XY::new((350.57326541515647-320.0)/320.0, (301.681534449599-320.0)/320.0),
+            XY::new((341.08579134528526-320.0)/320.0, (304.88220534660854-320.0)/320.0),
+            XY::new((331.3818629620819-320.0)/320.0, (307.2269258169942-320.0)/320.0),
+            XY::new((321.46577946477964-320.0)/320.0, (308.5693484766675-320.0)/320.0),
+            XY::new((311.4856336161622-320.0)/320.0, (308.412349481032-320.0)/320.0),
+            XY::new((302.10964029207173-320.0)/320.0, (305.23369128229314-320.0)/320.0),
+            XY::new((296.2213029336929-320.0)/320.0, (297.23920729637143-320.0)/320.0),
+            XY::new((292.0093761162319-320.0)/320.0, (288.18436236352863-320.0)/320.0),
+            XY::new((288.33911088599666-320.0)/320.0, (278.8893183914825-320.0)/320.0),
+            XY::new((284.9363872770267-320.0)/320.0, (269.47738487005296-320.0)/320.0),
+            XY::new((281.6928253326567-320.0)/320.0, (260.0247118461869-320.0)/320.0),
+            XY::new((278.53454052358995-320.0)/320.0, (250.53800682181114-320.0)/320.0),
+            XY::new((275.41611312945565-320.0)/320.0, (241.0357689301946-320.0)/320.0),
+            XY::new((272.30052194469135-320.0)/320.0, (231.53250790235793-320.0)/320.0),
+            XY::new((269.1490211174008-320.0)/320.0, (222.03210884703728-320.0)/320.0),
+            XY::new((265.9302157879585-320.0)/320.0, (212.5753197175075-320.0)/320.0),
+            XY::new((262.57665205515326-320.0)/320.0, (203.13947184051764-320.0)/320.0),
+            XY::new((259.0231473524345-320.0)/320.0, (193.8021353837871-320.0)/320.0),
+            XY::new((255.1087652066095-320.0)/320.0, (184.60137300634085-320.0)/320.0),
+            XY::new((250.48749057687368-320.0)/320.0, (175.74144869005409-320.0)/320.0),
+            XY::new((246.1174210968254-320.0)/320.0, (166.75475344675112-320.0)/320.0),
+            XY::new((243.6664605209751-320.0)/320.0, (157.08581652550535-320.0)/320.0),
+            XY::new((244.71176715038717-320.0)/320.0, (147.22067814148963-320.0)/320.0),
+            XY::new((249.6387582888326-320.0)/320.0, (138.58739286089664-320.0)/320.0),
+            XY::new((256.6207563751936-320.0)/320.0, (131.43683914005754-320.0)/320.0),
+            XY::new((264.2402025592653-320.0)/320.0, (124.96902640196843-320.0)/320.0),
+            XY::new((273.4318602335412-320.0)/320.0, (121.33634124762918-320.0)/320.0),
+            XY::new((283.4009054134554-320.0)/320.0, (121.5548203345749-320.0)/320.0),
+            XY::new((293.30891735930464-320.0)/320.0, (122.90082144180894-320.0)/320.0),
+            XY::new((303.28481487057115-320.0)/320.0, (123.43409106710503-320.0)/320.0),
+            XY::new((313.05341579904723-320.0)/320.0, (121.52159256739819-320.0)/320.0),
+            XY::new((321.59167814646383-320.0)/320.0, (116.40649364082725-320.0)/320.0),
+            XY::new((328.2452640961355-320.0)/320.0, (108.96266720173108-320.0)/320.0),
+            XY::new((331.78857029392384-320.0)/320.0, (99.73349976593745-320.0)/320.0),
+            XY::new((329.30760841031554-320.0)/320.0, (90.23895806470304-320.0)/320.0),
+            XY::new((322.35890986986453-320.0)/320.0, (83.11470849058591-320.0)/320.0),
+            XY::new((313.74400430121113-320.0)/320.0, (78.09157114578804-320.0)/320.0),
+            XY::new((304.40876821339134-320.0)/320.0, (74.48191183589398-320.0)/320.0),
+            XY::new((294.735635378348-320.0)/320.0, (71.99859143655442-320.0)/320.0),
+            XY::new((284.84270851478914-320.0)/320.0, (70.57759903220344-320.0)/320.0),
+            XY::new((274.85940221103954-320.0)/320.0, (70.45672664726881-320.0)/320.0),
+            XY::new((265.3191709695442-320.0)/320.0, (73.19624227409398-320.0)/320.0),
+            XY::new((257.86039888627823-320.0)/320.0, (79.74290770711377-320.0)/320.0),
+            XY::new((252.58990536667406-320.0)/320.0, (88.24054883355275-320.0)/320.0),
+            XY::new((248.41810748644173-320.0)/320.0, (97.32196846501901-320.0)/320.0),
+            XY::new((244.50019486368635-320.0)/320.0, (106.5232725656056-320.0)/320.0),
+            XY::new((240.32248419858516-320.0)/320.0, (115.60389757061378-320.0)/320.0),
+            XY::new((235.531021485338-320.0)/320.0, (124.37882446065778-320.0)/320.0),
+            XY::new((230.00461219973164-320.0)/320.0, (132.70762114887418-320.0)/320.0),
+            XY::new((226.51809652576077-320.0)/320.0, (142.0267839141425-320.0)/320.0),
+            XY::new((225.71335009284346-320.0)/320.0, (151.980038233038-320.0)/320.0),
+            XY::new((226.50907054742797-320.0)/320.0, (161.94023342335132-320.0)/320.0),
+            XY::new((228.1657176105576-320.0)/320.0, (171.798254885228-320.0)/320.0),
+            XY::new((230.25019363792848-320.0)/320.0, (181.58911434290985-320.0)/320.0),
+            XY::new((232.47457267103715-320.0)/320.0, (191.32951942625922-320.0)/320.0),
+            XY::new((234.63417891731487-320.0)/320.0, (201.08906378270592-320.0)/320.0),
+            XY::new((236.54788563982817-320.0)/320.0, (210.9167389084754-320.0)/320.0),
+            XY::new((238.02681171590694-320.0)/320.0, (220.80480643231482-320.0)/320.0),
+            XY::new((238.8741629886627-320.0)/320.0, (230.7499515938759-320.0)/320.0),
+            XY::new((238.87016360640524-320.0)/320.0, (240.7478393945098-320.0)/320.0),
+            XY::new((237.77465855455026-320.0)/320.0, (250.68288083133757-320.0)/320.0),
+            XY::new((235.37026463972086-320.0)/320.0, (260.38703288906584-320.0)/320.0),
+            XY::new((231.56847613574936-320.0)/320.0, (269.62020208979493-320.0)/320.0),
+            XY::new((225.94086604912067-320.0)/320.0, (277.86503014173655-320.0)/320.0),
+            XY::new((217.9729592330754-320.0)/320.0, (283.79637012753636-320.0)/320.0),
+            XY::new((208.32272456691368-320.0)/320.0, (286.1240170512157-320.0)/320.0),
+            XY::new((198.3637239819579-320.0)/320.0, (285.3540877323365-320.0)/320.0),
+            XY::new((188.71790350285096-320.0)/320.0, (282.7743380404871-320.0)/320.0),
+            XY::new((179.35719251761213-320.0)/320.0, (279.23828469131604-320.0)/320.0),
+            XY::new((170.19296565653755-320.0)/320.0, (275.2525848161662-320.0)/320.0),
+            XY::new((161.0768702766576-320.0)/320.0, (271.1483262129252-320.0)/320.0),
+            XY::new((151.87050199477702-320.0)/320.0, (267.233488750825-320.0)/320.0),
+            XY::new((142.74916797624263-320.0)/320.0, (263.1904869233859-320.0)/320.0),
+            XY::new((134.98888480119348-320.0)/320.0, (256.9545610914599-320.0)/320.0),
+            XY::new((130.88401972820517-320.0)/320.0, (247.9633453164657-320.0)/320.0),
+            XY::new((130.48484557523858-320.0)/320.0, (237.9834582332417-320.0)/320.0),
+            XY::new((131.3905074102478-320.0)/320.0, (228.02829761146683-320.0)/320.0),
+            XY::new((132.11279140257915-320.0)/320.0, (218.06182664133885-320.0)/320.0),
+            XY::new((131.8925947943324-320.0)/320.0, (208.0794949053634-320.0)/320.0),
+            XY::new((130.79897115408386-320.0)/320.0, (198.13777643251058-320.0)/320.0),
+            XY::new((132.3837942957878-320.0)/320.0, (188.33124563455578-320.0)/320.0),
+            XY::new((137.96597743133026-320.0)/320.0, (180.12805235404346-320.0)/320.0),
+            XY::new((145.75688285443468-320.0)/320.0, (173.87352602327707-320.0)/320.0),
+            XY::new((154.05852624669205-320.0)/320.0, (168.28579755513462-320.0)/320.0),
+            XY::new((162.00333330560417-320.0)/320.0, (162.2199322991242-320.0)/320.0),
+            XY::new((168.85203727095796-320.0)/320.0, (154.96990560863458-320.0)/320.0),
+            XY::new((173.93859162386508-320.0)/320.0, (146.3824792437628-320.0)/320.0),
+            XY::new((178.94411203723865-320.0)/320.0, (137.74238024074452-320.0)/320.0),
+            XY::new((185.4218615159765-320.0)/320.0, (130.14177354722167-320.0)/320.0),
+            XY::new((192.59302966326476-320.0)/320.0, (123.16697233595886-320.0)/320.0),
+            XY::new((199.90839751568214-320.0)/320.0, (116.35875387533814-320.0)/320.0),
+            XY::new((206.9730160432635-320.0)/320.0, (109.30007267906228-320.0)/320.0),
+            XY::new((213.4255244737165-320.0)/320.0, (101.65064088712897-320.0)/320.0),
+            XY::new((218.84628084163649-320.0)/320.0, (93.26210192420884-320.0)/320.0),
+            XY::new((222.9122885347111-320.0)/320.0, (84.13187884083499-320.0)/320.0),
+            XY::new((225.45627404742407-320.0)/320.0, (74.47249542172517-320.0)/320.0),
+            XY::new((226.54243306048681-320.0)/320.0, (64.5483695324602-320.0)/320.0),
+            XY::new((226.3748621409759-320.0)/320.0, (54.546824327857465-320.0)/320.0),
+            XY::new((225.1432031459455-320.0)/320.0, (44.627044607715504-320.0)/320.0),
+            XY::new((222.17685983873903-320.0)/320.0, (35.11642269968986-320.0)/320.0),
+            XY::new((214.81029196270742-320.0)/320.0, (28.9955281893909-320.0)/320.0),
+            XY::new((205.56406536588227-320.0)/320.0, (32.19266009949846-320.0)/320.0),
+            XY::new((198.17529925525088-320.0)/320.0, (38.91392668454675-320.0)/320.0),
+            XY::new((191.91555733702145-320.0)/320.0, (46.68912388637662-320.0)/320.0),
+            XY::new((186.3986270311696-320.0)/320.0, (55.037136122751974-320.0)/320.0),
+            XY::new((181.53448276701724-320.0)/320.0, (63.76533214672497-320.0)/320.0),
+            XY::new((177.36431772721915-320.0)/320.0, (72.85537037680537-320.0)/320.0),
+            XY::new((174.2713930169672-320.0)/320.0, (82.34574762292439-320.0)/320.0),
+            XY::new((174.4310839133989-320.0)/320.0, (92.21407621459103-320.0)/320.0),
+            XY::new((170.80412548065186-320.0)/320.0, (101.41238986968993-320.0)/320.0),
+            XY::new((164.7037393092236-320.0)/320.0, (109.33223063573124-320.0)/320.0),
+            XY::new((157.7911415901035-320.0)/320.0, (116.53941973157227-320.0)/320.0),
+            XY::new((150.40428809762352-320.0)/320.0, (123.27992548723822-320.0)/320.0),
+            XY::new((142.68330270373264-320.0)/320.0, (129.63523106317035-320.0)/320.0),
+            XY::new((134.68584354121745-320.0)/320.0, (135.63408844490255-320.0)/320.0),
+            XY::new((126.41474615648374-320.0)/320.0, (141.27475581366988-320.0)/320.0),
+            XY::new((117.89585511363694-320.0)/320.0, (146.47489488455352-320.0)/320.0),
+            XY::new((109.03470302402972-320.0)/320.0, (151.12471185147763-320.0)/320.0),
+            XY::new((99.76543330347049-320.0)/320.0, (154.85355802377336-320.0)/320.0),
+            XY::new((91.3126025398563-320.0)/320.0, (159.91745607952367-320.0)/320.0),
+            XY::new((86.53120088212759-320.0)/320.0, (168.62562807149615-320.0)/320.0),
+            XY::new((84.44914064484875-320.0)/320.0, (178.38344372954924-320.0)/320.0),
+            XY::new((83.86541487893511-320.0)/320.0, (188.3531909368331-320.0)/320.0),
+            XY::new((84.18849609375-320.0)/320.0, (198.34947265625-320.0)/320.0),
+            XY::new((85.11774875880613-320.0)/320.0, (208.30448827682602-320.0)/320.0),
+            XY::new((86.48301853222348-320.0)/320.0, (218.21060479524039-320.0)/320.0),
+            XY::new((88.17799449534664-320.0)/320.0, (228.0614493331169-320.0)/320.0),
+            XY::new((90.13628230173418-320.0)/320.0, (237.87794650878482-320.0)/320.0),
+            XY::new((92.30300695188349-320.0)/320.0, (247.63415380038316-320.0)/320.0),
+            XY::new((94.54742474698156-320.0)/320.0, (257.3782382130623-320.0)/320.0),
+            XY::new((96.49651574685238-320.0)/320.0, (267.1800862503052-320.0)/320.0),
+            XY::new((99.02784637154662-320.0)/320.0, (276.86948620796204-320.0)/320.0),
+            XY::new((102.3010757611785-320.0)/320.0, (286.29683917999273-320.0)/320.0),
+            XY::new((106.5866952862883-320.0)/320.0, (295.3274883389473-320.0)/320.0),
+            XY::new((112.22741096530459-320.0)/320.0, (303.5639317035675-320.0)/320.0),
+            XY::new((119.59888242172192-320.0)/320.0, (310.29662617683414-320.0)/320.0),
+            XY::new((128.63035600765608-320.0)/320.0, (314.46245197296145-320.0)/320.0))
+    );
+
+    let holed_paths = vec!(
+        (vec!(XY::new(-0.6,-0.6), XY::new( 0.6,-0.6), XY::new( 0.6, 0.6), XY::new(-0.6, 0.6)),
+            vec!(vec!(XY::new(-0.3,-0.3), XY::new(-0.3, 0.3), XY::new( 0.3, 0.3), XY::new( 0.3,-0.3)))),
+
+        (vec!(XY::new(-0.7,-0.5), XY::new( 0.0,-0.7), XY::new( 0.7,-0.5), XY::new( 0.8, 0.0), XY::new( 0.7, 0.5), 
+            XY::new( 0.0, 0.7), XY::new(-0.7, 0.5), XY::new(-0.8, 0.0)),
+            vec!(vec!(XY::new(-0.5, 0.1), XY::new(-0.3, 0.4), XY::new(-0.1, 0.1)),
+                vec!(XY::new(-0.5,-0.1), XY::new(-0.1,-0.1), XY::new(-0.3,-0.4)),
+                vec!(XY::new( 0.1, 0.0), XY::new( 0.3, 0.3), XY::new( 0.5, 0.2), XY::new( 0.4, 0.0), XY::new( 0.5,-0.2), XY::new( 0.3,-0.3)))),
+
+        (vec!(XY::new(-0.7,-0.6), XY::new( 0.7,-0.6), XY::new( 0.7, 0.6), XY::new(-0.7, 0.6)),
+            vec!(vec!(XY::new(-0.5,-0.4), XY::new(-0.5, 0.4), XY::new(-0.3, 0.4), XY::new(-0.3,-0.2), XY::new( 0.3,-0.2), 
+                    XY::new( 0.3, 0.4), XY::new( 0.5, 0.4), XY::new( 0.5,-0.4)),
+                vec!(XY::new(-0.1, 0.0), XY::new(-0.2, 0.3), XY::new( 0.0, 0.45), XY::new( 0.2, 0.3), XY::new( 0.1, 0.0))))
+    );
+
+    let tangled_paths = vec!(
+        vec!(XY::new(-0.6,-0.4), XY::new( 0.6, 0.4), XY::new( 0.6,-0.4), XY::new(-0.6, 0.4)),
+
+        vec!(XY::new( 0.0, 0.7), XY::new( 0.41,-0.57), XY::new(-0.67, 0.22), XY::new( 0.67, 0.22), XY::new(-0.41,-0.57)),
+
+        vec!(XY::new(-0.7,-0.2), XY::new( 0.3,-0.2), XY::new( 0.3, 0.5), XY::new(-0.2, 0.5), XY::new(-0.2,-0.5),
+            XY::new( 0.6,-0.5), XY::new( 0.6, 0.2), XY::new(-0.7, 0.2))
+    );
+
+    let filled_paths = vec!(
+        (vec!(vec!(XY::new( 0.0, 0.7), XY::new( 0.41,-0.57), XY::new(-0.67, 0.22), XY::new( 0.67, 0.22), XY::new(-0.41,-0.57))),
+            triangulator::FillRule::EvenOdd),
+
+        (vec!(vec!(XY::new(-0.7,-0.7), XY::new( 0.3,-0.7), XY::new( 0.3, 0.3), XY::new(-0.7, 0.3)),
+                vec!(XY::new(-0.3,-0.3), XY::new( 0.7,-0.3), XY::new( 0.7, 0.7), XY::new(-0.3, 0.7))),
+            triangulator::FillRule::EvenOdd),
+
+        (vec!(vec!(XY::new(-0.7,-0.7), XY::new( 0.7,-0.7), XY::new( 0.7, 0.7), XY::new(-0.7, 0.7)),
+                vec!(XY::new(-0.4,-0.4), XY::new(-0.4, 0.4), XY::new( 0.4, 0.4), XY::new( 0.4,-0.4)),
+                vec!(XY::new(-0.2,-0.2), XY::new( 0.2,-0.2), XY::new( 0.2, 0.2), XY::new(-0.2, 0.2))),
+            triangulator::FillRule::NonZero)
+    );
+
+    // Inputs the sweep has to be careful with: vertical edges, points in a line, repeated points, spikes and touching rings
+    let degenerate_paths = vec!(
+        (vec!(XY::new(-0.6,-0.6), XY::new(-0.2,-0.6), XY::new( 0.2,-0.6), XY::new( 0.6,-0.6), XY::new( 0.6,-0.2),
+            XY::new( 0.2,-0.2), XY::new( 0.2, 0.2), XY::new( 0.2, 0.6), XY::new(-0.2, 0.6), XY::new(-0.6, 0.6),
+            XY::new(-0.6, 0.2), XY::new(-0.6,-0.2)),
+            vec!()),
+
+        (vec!(XY::new(-0.7,-0.5), XY::new(-0.5,-0.5), XY::new(-0.5, 0.3), XY::new(-0.3, 0.3), XY::new(-0.3,-0.5),
+            XY::new(-0.1,-0.5), XY::new(-0.1, 0.3), XY::new( 0.1, 0.3), XY::new( 0.1,-0.5), XY::new( 0.3,-0.5),
+            XY::new( 0.3, 0.3), XY::new( 0.5, 0.3), XY::new( 0.5,-0.5), XY::new( 0.7,-0.5), XY::new( 0.7, 0.5),
+            XY::new(-0.7, 0.5)),
+            vec!()),
+
+        (vec!(XY::new(-0.5,-0.5), XY::new(-0.5,-0.5), XY::new( 0.5,-0.5), XY::new( 0.5, 0.0), XY::new( 0.5, 0.0),
+            XY::new( 0.5, 0.5), XY::new( 0.0, 0.5), XY::new( 0.0, 0.8), XY::new( 0.0, 0.5), XY::new(-0.5, 0.5),
+            XY::new(-0.5, 0.5)),
+            vec!()),
+
+        (vec!(XY::new(-0.6,-0.6), XY::new( 0.6,-0.6), XY::new( 0.6, 0.6), XY::new(-0.6, 0.6)),
+            vec!(vec!(XY::new(-0.6, 0.0), XY::new( 0.0, 0.3), XY::new( 0.3, 0.0), XY::new( 0.0,-0.3)),
+                vec!(XY::new( 0.3, 0.0), XY::new( 0.45, 0.15), XY::new( 0.45,-0.15)))),
+
+        (vec!(XY::new(-0.6,-0.6), XY::new( 0.0,-0.6), XY::new( 0.0, 0.0), XY::new( 0.6, 0.0), XY::new( 0.6, 0.6),
+            XY::new( 0.0, 0.6), XY::new( 0.0, 0.0), XY::new(-0.6, 0.0)),
+            vec!())
+    );
+
+    let mut rounded_rectangle = PathBuilder::new(XY::new(-0.5,-0.6), 0.002);
+    rounded_rectangle.line_to(XY::new( 0.5,-0.6)).arc_to(XY::new( 0.2, 0.2), 0.0, false, true, XY::new( 0.7,-0.4))
+        .line_to(XY::new( 0.7, 0.4)).arc_to(XY::new( 0.2, 0.2), 0.0, false, true, XY::new( 0.5, 0.6))
+        .line_to(XY::new(-0.5, 0.6)).arc_to(XY::new( 0.2, 0.2), 0.0, false, true, XY::new(-0.7, 0.4))
+        .line_to(XY::new(-0.7,-0.4)).arc_to(XY::new( 0.2, 0.2), 0.0, false, true, XY::new(-0.5,-0.6));
+
+    let mut heart = PathBuilder::new(XY::new( 0.0,-0.7), 0.002);
+    heart.cubic_to(XY::new( 0.4,-0.3), XY::new( 0.9, 0.1), XY::new( 0.5, 0.5))
+        .cubic_to(XY::new( 0.3, 0.7), XY::new( 0.0, 0.6), XY::new( 0.0, 0.35))
+        .cubic_to(XY::new( 0.0, 0.6), XY::new(-0.3, 0.7), XY::new(-0.5, 0.5))
+        .cubic_to(XY::new(-0.9, 0.1), XY::new(-0.4,-0.3), XY::new( 0.0,-0.7));
+
+    let mut wave = PathBuilder::new(XY::new(-0.8,-0.3), 0.002);
+    wave.quad_to(XY::new(-0.6, 0.1), XY::new(-0.4,-0.1)).quad_to(XY::new(-0.2,-0.3), XY::new( 0.0,-0.1))
+        .quad_to(XY::new( 0.2, 0.1), XY::new( 0.4,-0.1)).quad_to(XY::new( 0.6,-0.3), XY::new( 0.8,-0.1))
+        .line_to(XY::new( 0.8, 0.3)).arc_to(XY::new( 0.8, 0.4), 0.0, false, true, XY::new(-0.8, 0.3));
+
+    let mut eye_hole = curves::flatten_ellipse(XY::new( 0.0, 0.0), XY::new( 0.3, 0.15), 20.0, 0.002);
+    eye_hole.reverse();
+
+    let curved_paths = vec!(
+        (rounded_rectangle.build(), vec!()),
+        (heart.build(), vec!()),
+        (wave.build(), vec!()),
+        (curves::flatten_ellipse(XY::new( 0.0, 0.0), XY::new( 0.7, 0.45), 20.0, 0.002), vec!(eye_hole))
+    );
+
+    let mut outline = StrokeStyle::new(0.04);
+    outline.join = LineJoin::Round;
+    let mut dashed = StrokeStyle::new(0.03);
+    (dashed.dashes, dashed.cap) = (vec!(0.08, 0.05), LineCap::Round);
+    let mut zigzag = StrokeStyle::new(0.08);
+    zigzag.cap = LineCap::Square;
+
+    // Strokes go after the fills they outline so they're drawn on top
+    let stroked_paths = vec!(
+        (heart.build(), true, outline, true),
+        (rounded_rectangle.build(), true, dashed, true),
+        (vec!(XY::new(-0.7,-0.4), XY::new(-0.4, 0.4), XY::new(-0.1,-0.4), XY::new( 0.2, 0.4), XY::new( 0.5,-0.4), XY::new( 0.7, 0.0)),
+            false, zigzag, false)
+    );
+
+    let wall = vec!(vec!(XY::new(-0.8,-0.6), XY::new( 0.8,-0.6), XY::new( 0.8, 0.4), XY::new( 0.0, 0.8), XY::new(-0.8, 0.4)));
+    let windows = vec!(vec!(XY::new(-0.6,-0.2), XY::new(-0.2,-0.2), XY::new(-0.2, 0.2), XY::new(-0.6, 0.2)),
+        vec!(XY::new( 0.2,-0.2), XY::new( 0.6,-0.2), XY::new( 0.6, 0.2), XY::new( 0.2, 0.2)),
+        vec!(XY::new(-0.15,-0.6), XY::new( 0.15,-0.6), XY::new( 0.15, 0.0), XY::new(-0.15, 0.0)));
+    let disc = vec!(curves::flatten_ellipse(XY::new( 0.25, 0.1), XY::new( 0.45, 0.45), 0.0, 0.002));
+    let square = vec!(vec!(XY::new(-0.7,-0.6), XY::new( 0.1,-0.6), XY::new( 0.1, 0.2), XY::new(-0.7, 0.2)));
+
+    let boolean_paths = vec!(
+        (wall, windows, BooleanOp::Difference),
+        (square.clone(), disc.clone(), BooleanOp::Union),
+        (square.clone(), disc.clone(), BooleanOp::Intersection),
+        (square, disc, BooleanOp::Xor)
+    );
+
+    let dumbbell = vec!(vec!(XY::new(-0.8,-0.4), XY::new(-0.2,-0.4), XY::new(-0.2,-0.05), XY::new( 0.2,-0.05), XY::new( 0.2,-0.4), XY::new( 0.8,-0.4),
+        XY::new( 0.8, 0.4), XY::new( 0.2, 0.4), XY::new( 0.2, 0.05), XY::new(-0.2, 0.05), XY::new(-0.2, 0.4), XY::new(-0.8, 0.4)));
+    let room = vec!(vec!(XY::new(-0.6,-0.6), XY::new( 0.6,-0.6), XY::new( 0.6, 0.6), XY::new( 0.05, 0.6), XY::new( 0.05, 0.3), XY::new( 0.3, 0.3),
+        XY::new( 0.3,-0.3), XY::new(-0.3,-0.3), XY::new(-0.3, 0.3), XY::new(-0.05, 0.3), XY::new(-0.05, 0.6), XY::new(-0.6, 0.6)));
+    let arrow = vec!(vec!(XY::new(-0.6,-0.1), XY::new( 0.1,-0.1), XY::new( 0.1,-0.4), XY::new( 0.6, 0.0), XY::new( 0.1, 0.4), XY::new( 0.1, 0.1), XY::new(-0.6, 0.1)));
+
+    // Shrinking pulls the dumbbell apart in two and growing closes the room's doorway, leaving a hole
+    let offset_paths = vec!(
+        (dumbbell, -0.1, OffsetJoin::Miter),
+        (room, 0.08, OffsetJoin::Round),
+        (arrow.clone(), 0.15, OffsetJoin::Miter),
+        (arrow, 0.15, OffsetJoin::Square)
+    );
+
+    // The last shape again with far fewer points, as it might be drawn further away
+    let detailed = &paths[paths.len() - 1];
+    let simplified_paths = vec!(simplify::douglas_peucker(detailed, 0.01), simplify::visvalingam_whyatt(detailed, 0.0004));
+    log::info!("Simplifying took {} points down to {} and {}", detailed.len(), simplified_paths[0].len(), simplified_paths[1].len());
+
+    let mut triangulated = Vec::with_capacity(paths.len() + holed_paths.len() + tangled_paths.len() + filled_paths.len()
+        + degenerate_paths.len() + curved_paths.len() + stroked_paths.len() + boolean_paths.len() + offset_paths.len()
+        + simplified_paths.len());
+    for (i, (path, result)) in paths.iter().zip(batch::try_calc_batch(&paths)).enumerate() 
+    {
+        match result
+        {
+            Ok(mut triangles) =>
+            {
+                delaunay::flip_edges(path, &mut triangles);
+                let triangles = triangulator::to_triangles(path, &triangles);
+                for problem in validation::verify(path, &triangles).problems
+                    { log::error!("Shape {} was triangulated wrongly: {}", i, problem); }
+                triangulated.push(triangles);
+            },
+            Err(error) =>
+            {
+                log::error!("Shape {} can't be triangulated: {}", i, error);
+                for issue in validation::validate(path).issues
+                    { log::error!("    {}", issue); }
+            },
+        }
+    }
+    for (outer, holes) in holed_paths 
+    {
+        let triangles = triangulator::calc_with_holes_indexed(&outer, &holes);
+        let points = [vec!(outer), holes].concat().concat();
+        let mesh = delaunay::refine(&points, &triangles, &delaunay::Quality::new(20.0, 0.01));
+        log::info!("Refining a shape with holes added {} Steiner points", mesh.steiner_points);
+        triangulated.push(triangulator::to_triangles(&mesh.points, &mesh.triangles));
+    }
+    for path in tangled_paths 
+        { triangulated.push(triangulator::calc_untangled(&path)); }
+    for (contours, fill_rule) in filled_paths 
+        { triangulated.push(triangulator::calc_filled(&contours, fill_rule)); }
+    for (outer, holes) in degenerate_paths 
+        { triangulated.push(triangulator::calc_with_holes(&outer, &holes)); }
+    for (outer, holes) in curved_paths 
+        { triangulated.push(triangulator::calc_with_holes(&outer, &holes)); }
+    for (path, is_closed, style, is_filled) in stroked_paths 
+    {
+        let mut triangles = if is_filled { triangulator::calc(&path) } else { vec!() };
+        triangles.extend(stroke::stroke(&path, is_closed, &style));
+        triangulated.push(triangles);
+    }
+    for (subject, clip, operation) in boolean_paths 
+    {
+        let combined = booleans::calc(&subject, &clip, operation, triangulator::FillRule::NonZero);
+        triangulated.push(combined.iter().flat_map(|(outer, holes)| triangulator::calc_with_holes(outer, holes)).collect());
+    }
+    for (rings, distance, join) in offset_paths 
+    {
+        let moved = offset::offset(&rings, distance, join, 4.0, 0.002);
+        triangulated.push(moved.iter().flat_map(|(outer, holes)| triangulator::calc_with_holes(outer, holes)).collect());
+    }
+    for path in simplified_paths 
+        { triangulated.push(triangulator::calc(&path)); }
+
+    let mut shapes = vec!();
+    let mut rng = rand::rng();
+
+    for path_xys in triangulated 
+    {
+        let mut path_verts = Vec::with_capacity(path_xys.len()*3);
+        for xy in path_xys
+        {
+            let rand_col = [rng.random::<f32>(), rng.random::<f32>(), rng.random::<f32>()];
+            
+            path_verts.push(Vertex { position: [xy.0.x, xy.0.y, 0.0], color: rand_col });
+            path_verts.push(Vertex { position: [xy.1.x, xy.1.y, 0.0], color: rand_col });
+            path_verts.push(Vertex { position: [xy.2.x, xy.2.y, 0.0], color: rand_col });
+        }
+        shapes.push(path_verts);
+    };
+
+    shapes
+}
+
+// The same shapes as strips for drawing with TriangleStrip. Triangles share the points where they meet, so the colours
+// go on the points and blend across each triangle.
+pub fn make_strips(shapes: &[Vec<Vertex>]) -> Vec<(Vec<Vertex>, Vec<u32>)>
+{
+    let mut rng = rand::rng();
+    let mut strips_list = vec!();
+
+    for path_verts in shapes
+    {
+        let mut indices = HashMap::<(u32, u32), u32>::new();
+        let mut verts = vec!();
+        let mut triangles = Vec::with_capacity(path_verts.len() / 3);
+        for triangle in path_verts.chunks(3)
+        {
+            let mut corners = [0; 3];
+            for (corner, vert) in corners.iter_mut().zip(triangle)
+            {
+                let key = (vert.position[0].to_bits(), vert.position[1].to_bits());
+                *corner = *indices.entry(key).or_insert_with(||
+                {
+                    let rand_col = [rng.random::<f32>(), rng.random::<f32>(), rng.random::<f32>()];
+                    verts.push(Vertex { position: vert.position, color: rand_col });
+                    verts.len() as u32 - 1
+                });
+            }
+            triangles.push(corners);
+        }
+
+        let strip = strips::from_triangles(&triangles, Some(u32::MAX));
+        log::info!("{} triangles in strips take {} indices rather than {}", triangles.len(), strip.len(), triangles.len() * 3);
+        strips_list.push((verts, strip));
+    }
+
+    strips_list
 }
\ No newline at end of file
@@ -0,0 +1,480 @@
+use crate::XY;
+use crate::predicates;
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Quality
+{
+    pub min_angle: f32, // Degrees, refinement is only sure to finish up to about 20.7
+    pub max_area: f32,
+    pub max_steiner_points: usize,
+}
+
+impl Quality
+{
+    pub fn new(min_angle: f32, max_area: f32) -> Quality { Quality { min_angle, max_area, max_steiner_points: 100_000 } }
+}
+
+#[derive(Debug, Clone)]
+pub struct RefinedMesh
+{
+    pub points: Vec<XY>, // The input points, followed by the Steiner points that were added
+    pub triangles: Vec<[u32; 3]>,
+    pub steiner_points: usize,
+}
+
+// Whether p is strictly inside the circle that has a to b as its diameter
+fn encroaches(a: XY, b: XY, p: XY) -> bool
+{
+    (a.x as f64 - p.x as f64) * (b.x as f64 - p.x as f64) + (a.y as f64 - p.y as f64) * (b.y as f64 - p.y as f64) < 0.0
+}
+
+fn circumcenter(a: XY, b: XY, c: XY) -> XY
+{
+    let (bx, by) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let (cx, cy) = (c.x as f64 - a.x as f64, c.y as f64 - a.y as f64);
+    let d = 2.0 * (bx * cy - by * cx);
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    XY::new((a.x as f64 + (cy * b2 - by * c2) / d) as f32, (a.y as f64 + (bx * c2 - cx * b2) / d) as f32)
+}
+
+enum Located
+{
+    Inside(usize /*Triangle Index*/),
+    Blocked(u32, u32 /*Boundary Edge*/),
+    Lost,
+}
+
+// Triangles are kept counter-clockwise. An edge with no triangle on its other side is part of the polygon's
+// boundary, which is never flipped, so the boundary edges double up as the constraints of the triangulation.
+struct Mesh
+{
+    points: Vec<XY>,
+    triangles: Vec<[u32; 3]>,
+    edge_to_triangle: HashMap::<(u32, u32) /*Directed Edge*/, usize /*Triangle Index*/>,
+    touched: Vec<usize /*Triangle Index*/>,
+    scale: f64,
+}
+
+impl Mesh
+{
+    fn new(points: &[XY], triangles: &[[u32; 3]]) -> Mesh
+    {
+        let scale = points.iter().map(|xy| xy.x.abs().max(xy.y.abs())).fold(1.0, f32::max) as f64;
+        let mut mesh = Mesh
+        {
+            points: points.to_vec(),
+            triangles: Vec::with_capacity(triangles.len()),
+            edge_to_triangle: HashMap::with_capacity(triangles.len() * 3),
+            touched: Vec::new(),
+            scale,
+        };
+        for triangle in triangles
+            { mesh.set_triangle(mesh.triangles.len(), *triangle); }
+        mesh.touched.clear();
+        mesh
+    }
+
+    fn is_boundary(&self, a: u32, b: u32) -> bool
+    {
+        !self.edge_to_triangle.contains_key(&(b, a))
+    }
+
+    fn opposite(&self, triangle: usize, a: u32, b: u32) -> u32
+    {
+        *self.triangles[triangle].iter().find(|i| **i != a && **i != b).unwrap()
+    }
+
+    fn set_triangle(&mut self, index: usize, triangle: [u32; 3])
+    {
+        if index == self.triangles.len()
+            { self.triangles.push(triangle); }
+        else
+        {
+            let old = self.triangles[index];
+            for i in 0..3
+            {
+                let edge = (old[i], old[(i + 1) % 3]);
+                if self.edge_to_triangle.get(&edge) == Some(&index)
+                    { self.edge_to_triangle.remove(&edge); }
+            }
+            self.triangles[index] = triangle;
+        }
+
+        for i in 0..3
+            { self.edge_to_triangle.insert((triangle[i], triangle[(i + 1) % 3]), index); }
+        self.touched.push(index);
+    }
+
+    // Sorted, so the flips happen in the same order every time and tie the same way
+    fn interior_edges(&self) -> Vec<(u32, u32)>
+    {
+        let mut edges: Vec<(u32, u32)> = self.edge_to_triangle.keys()
+            .filter(|(a, b)| a < b && !self.is_boundary(*a, *b))
+            .copied()
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    // Flips edges until every triangle's circumcircle is empty of its neighbours' far corners
+    fn legalize(&mut self, mut unchecked: Vec<(u32, u32)>)
+    {
+        while let Some((a, b)) = unchecked.pop()
+        {
+            let (Some(&t1), Some(&t2)) = (self.edge_to_triangle.get(&(a, b)), self.edge_to_triangle.get(&(b, a)))
+                else { continue; };
+
+            let c = self.opposite(t1, a, b);
+            let d = self.opposite(t2, a, b);
+            let (pa, pb, pc, pd) = (self.points[a as usize], self.points[b as usize], self.points[c as usize], self.points[d as usize]);
+
+            if predicates::incircle(pa, pb, pc, pd) <= 0.0
+                { continue; }
+
+            // A zero area triangle has every point on its circle, flipping it would fold the mesh
+            if predicates::orient2d(pa, pd, pc) <= 0.0 || predicates::orient2d(pd, pb, pc) <= 0.0
+                { continue; }
+
+            self.set_triangle(t1, [a, d, c]);
+            self.set_triangle(t2, [d, b, c]);
+            unchecked.extend([(a, d), (d, b), (b, c), (c, a)]);
+        }
+    }
+
+    // Walks across the mesh from a triangle towards p, stopping early if the polygon's boundary is in the way
+    fn locate(&self, mut triangle: usize, p: XY) -> Located
+    {
+        for _ in 0..self.triangles.len()
+        {
+            let t = self.triangles[triangle];
+            let crossed = (0..3)
+                .map(|i| (t[i], t[(i + 1) % 3]))
+                .find(|(a, b)| predicates::orient2d(self.points[*a as usize], self.points[*b as usize], p) < 0.0);
+
+            match crossed
+            {
+                None => return Located::Inside(triangle),
+                Some((a, b)) if self.is_boundary(a, b) => return Located::Blocked(a, b),
+                Some((a, b)) => triangle = self.edge_to_triangle[&(b, a)],
+            }
+        }
+
+        Located::Lost
+    }
+
+    fn insert_in_triangle(&mut self, triangle: usize, p: XY) -> u32
+    {
+        let v = self.points.len() as u32;
+        self.points.push(p);
+
+        let [a, b, c] = self.triangles[triangle];
+        self.set_triangle(triangle, [a, b, v]);
+        self.set_triangle(self.triangles.len(), [b, c, v]);
+        self.set_triangle(self.triangles.len(), [c, a, v]);
+        self.legalize(vec!((a, b), (b, c), (c, a)));
+        v
+    }
+
+    fn edge_through(&self, triangle: usize, p: XY) -> Option<(u32, u32)>
+    {
+        let t = self.triangles[triangle];
+        (0..3).map(|i| (t[i], t[(i + 1) % 3]))
+            .find(|(a, b)| predicates::orient2d(self.points[*a as usize], self.points[*b as usize], p) == 0.0)
+    }
+
+    fn insert_on_edge(&mut self, a: u32, b: u32, p: XY) -> u32
+    {
+        let v = self.points.len() as u32;
+        self.points.push(p);
+
+        let mut unchecked = Vec::with_capacity(4);
+        for (a, b) in [(a, b), (b, a)]
+        {
+            if let Some(&t) = self.edge_to_triangle.get(&(a, b))
+            {
+                let c = self.opposite(t, a, b);
+                self.set_triangle(t, [a, v, c]);
+                self.set_triangle(self.triangles.len(), [v, b, c]);
+                unchecked.extend([(b, c), (c, a)]);
+            }
+        }
+        self.legalize(unchecked);
+        v
+    }
+
+    // The boundary edges that p would end up joined to if it were inserted, and whose diameter circle it's inside
+    fn encroached_by(&self, triangle: usize, p: XY) -> Vec<(u32, u32)>
+    {
+        let mut encroached = Vec::new();
+        let mut visited = HashSet::from([triangle]);
+        let mut unvisited = vec!(triangle);
+        while let Some(t) = unvisited.pop()
+        {
+            let tri = self.triangles[t];
+            for i in 0..3
+            {
+                let (a, b) = (tri[i], tri[(i + 1) % 3]);
+                let (pa, pb) = (self.points[a as usize], self.points[b as usize]);
+                match self.edge_to_triangle.get(&(b, a))
+                {
+                    None => if encroaches(pa, pb, p) { encroached.push((a, b)); },
+                    Some(&other) =>
+                    {
+                        let c = self.opposite(other, a, b);
+                        if !visited.contains(&other) && predicates::incircle(pb, pa, self.points[c as usize], p) > 0.0
+                        {
+                            visited.insert(other);
+                            unvisited.push(other);
+                        }
+                    }
+                }
+            }
+        }
+        encroached
+    }
+
+    fn is_encroached(&self, a: u32, b: u32) -> bool
+    {
+        let Some(&t) = self.edge_to_triangle.get(&(a, b))
+            else { return false; };
+        let c = self.opposite(t, a, b);
+        self.is_boundary(a, b) && encroaches(self.points[a as usize], self.points[b as usize], self.points[c as usize])
+    }
+}
+
+// Turns the triangles into a constrained Delaunay triangulation, they have to be counter-clockwise as they come out of the triangulator
+pub fn flip_edges(points: &[XY], triangles: &mut [[u32; 3]])
+{
+    let mut mesh = Mesh::new(points, triangles);
+    mesh.legalize(mesh.interior_edges());
+    triangles.copy_from_slice(&mesh.triangles);
+}
+
+struct Refiner
+{
+    mesh: Mesh,
+    quality: Quality,
+    input_count: usize,
+    input_edge: HashMap::<u32 /*Steiner Point Index*/, (u32, u32) /*Input Edge it was put on*/>,
+    unsplittable: HashSet::<(u32, u32)>,
+    unfixable: HashSet::<[u32; 3]>,
+}
+
+impl Refiner
+{
+    fn is_bad(&self, triangle: usize) -> bool
+    {
+        let t = self.mesh.triangles[triangle];
+        let p = t.map(|i| self.mesh.points[i as usize]);
+        let area = predicates::orient2d(p[0], p[1], p[2]) / 2.0;
+        if area > self.quality.max_area as f64
+            { return true; }
+
+        for i in 0..3
+        {
+            let (a, b, c) = (p[i], p[(i + 1) % 3], p[(i + 2) % 3]);
+            let u = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+            let v = (c.x as f64 - a.x as f64, c.y as f64 - a.y as f64);
+            let angle = (u.0 * v.1 - u.1 * v.0).abs().atan2(u.0 * v.0 + u.1 * v.1).to_degrees();
+            if angle >= self.quality.min_angle as f64
+                { continue; }
+
+            // An angle between two boundary edges is part of the input, no amount of splitting gets rid of it.
+            // Neither does splitting the edge across from a small input angle, that only makes a smaller copy of it.
+            let (near, far) = (t[(i + 1) % 3], t[(i + 2) % 3]);
+            let input_angle = self.mesh.is_boundary(t[i], near) && self.mesh.is_boundary(far, t[i]);
+            if !input_angle && !self.across_input_angle(near, far)
+                { return true; }
+        }
+
+        false
+    }
+
+    fn across_input_angle(&self, a: u32, b: u32) -> bool
+    {
+        match (self.input_edge.get(&a), self.input_edge.get(&b))
+        {
+            (Some(ea), Some(eb)) => ea != eb && (ea.0 == eb.0 || ea.0 == eb.1 || ea.1 == eb.0 || ea.1 == eb.1),
+            _ => false,
+        }
+    }
+
+    // Edges running off an input vertex are split on circles around it with power of two radii, so splits
+    // coming from two edges either side of a small angle line up with each other rather than chasing each other in
+    fn split_edge(&mut self, a: u32, b: u32) -> bool
+    {
+        if self.unsplittable.contains(&(a, b))
+            { return false; }
+
+        let (pa, pb) = (self.mesh.points[a as usize], self.mesh.points[b as usize]);
+        let length = (pb.x as f64 - pa.x as f64).hypot(pb.y as f64 - pa.y as f64);
+        let input = |i: u32| (i as usize) < self.input_count;
+        let t = match (input(a), input(b))
+        {
+            (true, false) => 2.0f64.powf((length / 2.0).log2().round()) / length,
+            (false, true) => 1.0 - 2.0f64.powf((length / 2.0).log2().round()) / length,
+            _ => 0.5,
+        };
+        let split = XY::new((pa.x as f64 + (pb.x as f64 - pa.x as f64) * t) as f32, (pa.y as f64 + (pb.y as f64 - pa.y as f64) * t) as f32);
+
+        // Below this the split point can't be told apart from the ends any more
+        if length < self.mesh.scale * 1e-5 || (split.x == pa.x && split.y == pa.y) || (split.x == pb.x && split.y == pb.y)
+        {
+            self.unsplittable.insert((a, b));
+            return false;
+        }
+
+        let input_edge = *self.input_edge.get(&a).or(self.input_edge.get(&b)).unwrap_or(&(a, b));
+        let v = self.mesh.insert_on_edge(a, b, split);
+        self.input_edge.insert(v, input_edge);
+        true
+    }
+
+    fn fix_triangle(&mut self, t: usize)
+    {
+        let [a, b, c] = self.mesh.triangles[t].map(|i| self.mesh.points[i as usize]);
+        let center = circumcenter(a, b, c);
+        if !center.x.is_finite() || !center.y.is_finite()
+        {
+            self.unfixable.insert(self.mesh.triangles[t]);
+            return;
+        }
+
+        match self.mesh.locate(t, center)
+        {
+            Located::Inside(container) =>
+            {
+                // The boundary goes first, the triangle gets another go once it has been split
+                let encroached = self.mesh.encroached_by(container, center);
+                let duplicate = self.mesh.triangles[container].iter().any(|i| { let p = self.mesh.points[*i as usize]; p.x == center.x && p.y == center.y });
+                if !encroached.is_empty()
+                {
+                    let mut split = false;
+                    for (a, b) in encroached
+                        { split |= self.split_edge(a, b); }
+                    if !split
+                        { self.unfixable.insert(self.mesh.triangles[t]); }
+                }
+                else if duplicate
+                    { self.unfixable.insert(self.mesh.triangles[t]); }
+                else if let Some((a, b)) = self.mesh.edge_through(container, center)
+                    { self.mesh.insert_on_edge(a, b, center); }
+                else
+                    { self.mesh.insert_in_triangle(container, center); }
+            },
+            Located::Blocked(a, b) =>
+            {
+                if !self.split_edge(a, b)
+                    { self.unfixable.insert(self.mesh.triangles[t]); }
+            },
+            Located::Lost => { self.unfixable.insert(self.mesh.triangles[t]); },
+        }
+    }
+}
+
+// Ruppert's algorithm, boundary edges with a point inside their diameter circle are split and
+// triangles that are too thin or too big get a point at their circumcenter, until the mesh meets the quality
+pub fn refine(points: &[XY], triangles: &[[u32; 3]], quality: &Quality) -> RefinedMesh
+{
+    let mut refiner = Refiner
+    {
+        mesh: Mesh::new(points, triangles),
+        quality: *quality,
+        input_count: points.len(),
+        input_edge: HashMap::new(),
+        unsplittable: HashSet::new(),
+        unfixable: HashSet::new(),
+    };
+    let mesh = &mut refiner.mesh;
+    mesh.legalize(mesh.interior_edges());
+    mesh.touched.clear();
+
+    let mut suspect_edges: Vec<(u32, u32)> = mesh.edge_to_triangle.keys().copied().filter(|(a, b)| mesh.is_boundary(*a, *b)).collect();
+    suspect_edges.sort();
+    let mut suspect_triangles: Vec<usize> = (0..mesh.triangles.len()).collect();
+
+    while refiner.mesh.points.len() - points.len() < quality.max_steiner_points
+    {
+        if let Some((a, b)) = suspect_edges.pop()
+        {
+            if refiner.mesh.is_encroached(a, b)
+                { refiner.split_edge(a, b); }
+        }
+        else if let Some(t) = suspect_triangles.pop()
+        {
+            let triangle = refiner.mesh.triangles[t];
+            if refiner.is_bad(t) && !refiner.unfixable.contains(&triangle)
+            {
+                refiner.fix_triangle(t);
+
+                // Splitting the boundary first may have left this triangle as it was
+                if refiner.mesh.triangles[t] == triangle
+                    { suspect_triangles.push(t); }
+            }
+        }
+        else
+            { break; }
+
+        // Everything that changed shape has to be looked at again
+        let mesh = &mut refiner.mesh;
+        for t in std::mem::take(&mut mesh.touched)
+        {
+            suspect_triangles.push(t);
+            let tri = mesh.triangles[t];
+            for i in 0..3
+            {
+                let (a, b) = (tri[i], tri[(i + 1) % 3]);
+                if mesh.is_boundary(a, b)
+                    { suspect_edges.push((a, b)); }
+            }
+        }
+    }
+
+    RefinedMesh
+    {
+        steiner_points: refiner.mesh.points.len() - points.len(),
+        points: refiner.mesh.points,
+        triangles: refiner.mesh.triangles,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator;
+
+    fn star() -> Vec<XY>
+    {
+        (0..40).map(|i|
+        {
+            let (angle, radius) = (i as f32 * std::f32::consts::TAU / 40.0, if i % 2 == 0 { 1.0 } else { 0.4 });
+            XY::new(radius * angle.cos(), radius * angle.sin())
+        }).collect()
+    }
+
+    #[test]
+    fn flips_come_out_the_same_every_time()
+    {
+        let points = star();
+        let flipped = || { let mut triangles = triangulator::calc_indexed(&points); flip_edges(&points, &mut triangles); triangles };
+        let first = flipped();
+        for _ in 0..10
+            { assert_eq!(flipped(), first); }
+    }
+
+    #[test]
+    fn refinement_comes_out_the_same_every_time()
+    {
+        let points = star();
+        let triangles = triangulator::calc_indexed(&points);
+        let quality = Quality { min_angle: 20.0, max_area: 0.001, max_steiner_points: 10_000 };
+        let refined = || { let mesh = refine(&points, &triangles, &quality); (mesh.points.iter().map(|xy| (xy.x, xy.y)).collect::<Vec<_>>(), mesh.triangles) };
+        let first = refined();
+        assert!(first.0.len() > points.len());
+        for _ in 0..10
+            { assert_eq!(refined(), first); }
+    }
+}
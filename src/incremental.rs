@@ -0,0 +1,511 @@
+use crate::XY;
+use crate::scalar::Scalar;
+use crate::triangulator::{self, TriangulationError};
+use crate::validation;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Edit<T = f32>
+{
+    Move { vertex: u32, to: XY<T> },
+    Insert { after: u32, at: XY<T> }, // The new point goes on the end of points, between after and the vertex after it on the ring
+    Delete { vertex: u32 },
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Update
+{
+    Unchanged, // Only the point moved, every triangle is still the same
+    Local { triangles: usize }, // How many triangles round the edit were made again
+    Rebuilt,
+}
+
+// A triangulated ring that can be edited a vertex at a time. Vertices keep their index for as long as they're on
+// the ring, deleted ones stay in points with nothing using them. The triangles go counter-clockwise whichever way
+// round the ring goes.
+#[derive(Debug, Clone)]
+pub struct Triangulation<T = f32>
+{
+    points: Vec<XY<T>>,
+    next: Vec<u32>, // Counter-clockwise round the ring, u32::MAX once deleted
+    previous: Vec<u32>,
+    first: u32, // Any vertex still on the ring
+    is_clockwise: bool, // Which way the ring came in, and goes back out
+    triangles: Vec<[u32; 3]>,
+    around: Vec<Vec<usize /*Triangle Index*/>>,
+    edges: EdgeGrid<T>,
+}
+
+// The ring's edges filed under each cell of a grid they pass through, so a new edge is only checked against the ones
+// near it. The cells are about as big as an edge usually is, and only the ones with edges in are kept. Each edge is
+// filed by the vertex it starts from, along with where its ends were then to find it again by.
+#[derive(Debug, Clone)]
+struct EdgeGrid<T>
+{
+    origin: (f64, f64),
+    side: f64,
+    cells: HashMap<(i64 /*Column*/, i64 /*Row*/), Vec<u32 /*Start Vertex*/>>,
+    long: Vec<u32 /*Start Vertex*/>, // Edges across too many cells to file, every new edge is checked against them
+    filed: Vec<Option<(XY<T>, XY<T>)>>,
+}
+
+impl<T: Scalar> EdgeGrid<T>
+{
+    const MOST_CELLS: usize = 256;
+
+    fn new(points: &[XY<T>]) -> EdgeGrid<T>
+    {
+        let to_f64 = |p: XY<T>| (p.x.to_f64(), p.y.to_f64());
+        let mut lengths: Vec<f64> = (0..points.len()).map(|i|
+        {
+            let (a, b) = (to_f64(points[i]), to_f64(points[(i + 1) % points.len()]));
+            (b.0 - a.0).hypot(b.1 - a.1)
+        }).collect();
+        let middle = lengths.len() / 2;
+        let side = *lengths.select_nth_unstable_by(middle, f64::total_cmp).1;
+
+        let origin = to_f64(points[0]);
+        let side = if side > 0.0 && side.is_finite() { side } else { 1.0 };
+        EdgeGrid { origin, side, cells: HashMap::new(), long: vec!(), filed: vec!() }
+    }
+
+    // Every cell the edge passes through, and any it only just misses so rounding can't leave one out. None if that's
+    // too many to be worth it.
+    fn cells(&self, a: XY<T>, b: XY<T>) -> Option<Vec<(i64, i64)>>
+    {
+        let (a, b) = ((a.x.to_f64(), a.y.to_f64()), (b.x.to_f64(), b.y.to_f64()));
+        let (a, b) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+        if ![a.0, a.1, b.0, b.1].iter().all(|c| c.is_finite())
+            { return None; }
+
+        let column = |x: f64| ((x - self.origin.0) / self.side).floor();
+        let row = |y: f64| ((y - self.origin.1) / self.side).floor();
+        let (first, last) = (column(a.0), column(b.0));
+        let is_far = |cell: f64| cell.abs() > 1e15;
+        if [first, last, row(a.1), row(b.1)].into_iter().any(is_far)
+            || last - first >= Self::MOST_CELLS as f64 || (row(a.1) - row(b.1)).abs() >= Self::MOST_CELLS as f64
+            { return None; }
+
+        let y_at = |x: f64| if b.0 > a.0 { a.1 + (b.1 - a.1) * ((x - a.0) / (b.0 - a.0)).clamp(0.0, 1.0) } else { a.1 };
+        let mut cells = vec!();
+        let margin = self.side / 64.0;
+        for c in first as i64..=last as i64
+        {
+            // The part of the edge over the column, reaching a little way into the cells round it
+            let left = if c == first as i64 { a.0 } else { self.origin.0 + c as f64 * self.side - margin };
+            let right = if c == last as i64 { b.0 } else { self.origin.0 + (c + 1) as f64 * self.side + margin };
+            let (y_left, y_right) = (y_at(left), y_at(right));
+            let (low, high) = (row(y_left.min(y_right) - margin) as i64, row(y_left.max(y_right) + margin) as i64);
+            cells.extend((low..=high).map(|r| (c, r)));
+        }
+        (cells.len() <= Self::MOST_CELLS).then_some(cells)
+    }
+
+    fn insert(&mut self, v: u32, a: XY<T>, b: XY<T>)
+    {
+        match self.cells(a, b)
+        {
+            Some(cells) => for cell in cells { self.cells.entry(cell).or_default().push(v); },
+            None => self.long.push(v),
+        }
+        if self.filed.len() <= v as usize
+            { self.filed.resize(v as usize + 1, None); }
+        self.filed[v as usize] = Some((a, b));
+    }
+
+    fn remove(&mut self, v: u32)
+    {
+        let Some(Some((a, b))) = self.filed.get(v as usize).copied() else { return; };
+        match self.cells(a, b)
+        {
+            Some(cells) => for cell in cells
+            {
+                if let Some(filed) = self.cells.get_mut(&cell)
+                {
+                    filed.retain(|&u| u != v);
+                    if filed.is_empty()
+                        { self.cells.remove(&cell); }
+                }
+            },
+            None => self.long.retain(|&u| u != v),
+        }
+        self.filed[v as usize] = None;
+    }
+
+    // The start of every edge that might meet the one from a to b, or None if they all might
+    fn near(&self, a: XY<T>, b: XY<T>) -> Option<Vec<u32>>
+    {
+        let cells = self.cells(a, b)?;
+        Some(cells.iter().filter_map(|cell| self.cells.get(cell)).flatten().chain(&self.long).copied().collect())
+    }
+}
+
+// Whether p is on the segment from a to b, given it's on the line through them
+fn within<T: Scalar>(a: XY<T>, b: XY<T>, p: XY<T>) -> bool
+{
+    ((a.x <= p.x && p.x <= b.x) || (b.x <= p.x && p.x <= a.x)) && ((a.y <= p.y && p.y <= b.y) || (b.y <= p.y && p.y <= a.y))
+}
+
+// Whether the boxes round the two edges don't even meet, which is most of them
+fn apart<T: Scalar>(a: XY<T>, b: XY<T>, c: XY<T>, d: XY<T>) -> bool
+{
+    let below = |p: T, q: T, r: T, s: T| p < r && p < s && q < r && q < s;
+    below(a.x, b.x, c.x, d.x) || below(c.x, d.x, a.x, b.x) || below(a.y, b.y, c.y, d.y) || below(c.y, d.y, a.y, b.y)
+}
+
+fn touches<T: Scalar>(a: XY<T>, b: XY<T>, c: XY<T>, d: XY<T>) -> bool
+{
+    let (d1, d2, d3, d4) = (T::orient2d(c, d, a), T::orient2d(c, d, b), T::orient2d(a, b, c), T::orient2d(a, b, d));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0
+        { return true; }
+    (d1 == 0.0 && within(c, d, a)) || (d2 == 0.0 && within(c, d, b)) || (d3 == 0.0 && within(a, b, c)) || (d4 == 0.0 && within(a, b, d))
+}
+
+impl<T: Scalar> Triangulation<T>
+{
+    pub fn new(points: &[XY<T>]) -> Result<Triangulation<T>, TriangulationError>
+    {
+        let triangles = triangulator::try_calc_indexed(points)?;
+        let count = points.len() as u32;
+
+        // The edge from the first point to the second is in one triangle, which goes along it if the ring is counter-clockwise
+        let is_clockwise = triangles.iter().any(|triangle| (0..3).any(|k| triangle[k] == 1 && triangle[(k + 1) % 3] == 0));
+        let (next, previous): (Vec<u32>, Vec<u32>) = (0..count).map(|i| ((i + 1) % count, (i + count - 1) % count))
+            .map(|(after, before)| if is_clockwise { (before, after) } else { (after, before) })
+            .unzip();
+
+        let edges = EdgeGrid::new(points);
+        let mut triangulation = Triangulation { points: points.to_vec(), next, previous, first: 0, is_clockwise, triangles: vec!(), around: vec!(), edges };
+        triangulation.set_triangles(triangles);
+        for v in 0..count
+            { triangulation.file_edge(v); }
+        Ok(triangulation)
+    }
+
+    pub fn points(&self) -> &[XY<T>] { &self.points }
+    pub fn triangles(&self) -> &[[u32; 3]] { &self.triangles }
+
+    // The vertices still on the ring, going round the same way as the points that came in
+    pub fn ring(&self) -> Vec<u32>
+    {
+        let mut ring = self.cycle();
+        if self.is_clockwise
+            { ring[1..].reverse(); }
+        ring
+    }
+
+    fn cycle(&self) -> Vec<u32>
+    {
+        let mut ring = vec!(self.first);
+        let mut at = self.next[self.first as usize];
+        while at != self.first
+        {
+            ring.push(at);
+            at = self.next[at as usize];
+        }
+        ring
+    }
+
+    // Only the triangles touching the edit are made again, unless the edit takes the ring across some of the rest of
+    // it, in which case it's all done again. An edit that would leave the ring not simple is turned down and nothing
+    // changes.
+    pub fn apply(&mut self, edit: Edit<T>) -> Result<Update, TriangulationError>
+    {
+        let (Edit::Move { vertex, .. } | Edit::Insert { after: vertex, .. } | Edit::Delete { vertex }) = edit;
+        if self.next.get(vertex as usize).is_none_or(|&next| next == u32::MAX)
+            { return Err(TriangulationError::NoSuchVertex { index: vertex as usize }); }
+
+        // The edges out of these are the ones the edit changes, they're filed again once it's gone through
+        let after = |v: u32| if self.is_clockwise { self.previous[v as usize] } else { v };
+        let changed = match edit
+        {
+            Edit::Move { vertex, .. } | Edit::Delete { vertex } => [self.previous[vertex as usize], vertex],
+            Edit::Insert { after: vertex, .. } => [after(vertex), self.points.len() as u32],
+        };
+
+        let update = match edit
+        {
+            Edit::Move { vertex, to } => self.move_vertex(vertex, to),
+            Edit::Insert { after: vertex, at } => self.insert_vertex(after(vertex), at),
+            Edit::Delete { vertex } => self.delete_vertex(vertex),
+        }?;
+
+        for v in changed
+            { self.file_edge(v); }
+        Ok(update)
+    }
+
+    fn file_edge(&mut self, v: u32)
+    {
+        self.edges.remove(v);
+        let next = self.next[v as usize];
+        if next != u32::MAX
+            { self.edges.insert(v, self.points[v as usize], self.points[next as usize]); }
+    }
+
+    fn move_vertex(&mut self, v: u32, to: XY<T>) -> Result<Update, TriangulationError>
+    {
+        let old = self.points[v as usize];
+        self.points[v as usize] = to;
+
+        let (before, after) = (self.previous[v as usize], self.next[v as usize]);
+        if let Some((fan, link)) = self.fan(v) && self.is_clear(&[(before, v), (v, after)])
+        {
+            // Moving round inside the triangles it's already in doesn't need any new ones
+            let points = &self.points;
+            if fan.iter().all(|&t| { let [a, b, c] = self.triangles[t]; T::orient2d(points[a as usize], points[b as usize], points[c as usize]) > 0.0 })
+                { return Ok(Update::Unchanged); }
+
+            if let Some(update) = self.replace(&fan, &[vec!(v), link].concat())
+                { return Ok(update); }
+        }
+
+        self.rebuild().inspect_err(|_| self.points[v as usize] = old)
+    }
+
+    fn insert_vertex(&mut self, a: u32, at: XY<T>) -> Result<Update, TriangulationError>
+    {
+        let b = self.next[a as usize];
+        let p = self.points.len() as u32;
+        let inside = self.around[a as usize].iter().copied()
+            .find(|&t| (0..3).any(|k| self.triangles[t][k] == a && self.triangles[t][(k + 1) % 3] == b));
+
+        self.points.push(at);
+        self.around.push(vec!());
+        self.next.push(b);
+        self.previous.push(a);
+        self.next[a as usize] = p;
+        self.previous[b as usize] = p;
+
+        if let Some(t) = inside && self.is_clear(&[(a, p), (p, b)])
+        {
+            // The triangle along the edge just gets the new point in its corner
+            let c = self.triangles[t].into_iter().find(|&v| v != a && v != b).unwrap();
+            if let Some(update) = self.replace(&[t], &[a, p, b, c])
+                { return Ok(update); }
+        }
+
+        self.rebuild().inspect_err(|_|
+        {
+            self.next[a as usize] = b;
+            self.previous[b as usize] = a;
+            self.points.pop();
+            self.around.pop();
+            self.next.pop();
+            self.previous.pop();
+        })
+    }
+
+    fn delete_vertex(&mut self, v: u32) -> Result<Update, TriangulationError>
+    {
+        let (before, after) = (self.previous[v as usize], self.next[v as usize]);
+        if self.next[after as usize] == before
+            { return Err(TriangulationError::TooFewPoints { count: 2 }); }
+
+        let (fan, first) = (self.fan(v), self.first);
+        self.next[before as usize] = after;
+        self.previous[after as usize] = before;
+        (self.next[v as usize], self.previous[v as usize]) = (u32::MAX, u32::MAX);
+        if self.first == v
+            { self.first = after; }
+
+        if let Some((fan, link)) = fan && self.is_clear(&[(before, after)]) && let Some(update) = self.replace(&fan, &link)
+            { return Ok(update); }
+
+        self.rebuild().inspect_err(|_|
+        {
+            (self.next[v as usize], self.previous[v as usize]) = (after, before);
+            self.next[before as usize] = v;
+            self.previous[after as usize] = v;
+            self.first = first;
+        })
+    }
+
+    // The triangles round v, and the vertices across from it going round from the one after it to the one before
+    fn fan(&self, v: u32) -> Option<(Vec<usize>, Vec<u32>)>
+    {
+        let fan = self.around[v as usize].clone();
+        let across = |x: u32| fan.iter().map(|&t| self.triangles[t]).find_map(|triangle|
+        {
+            let k = (0..3).find(|&k| triangle[k] == v).unwrap();
+            (triangle[(k + 1) % 3] == x).then_some(triangle[(k + 2) % 3])
+        });
+
+        let mut link = vec!(self.next[v as usize]);
+        while link.len() <= fan.len()
+            { link.push(across(*link.last().unwrap())?); }
+        (*link.last().unwrap() == self.previous[v as usize]).then_some((fan, link))
+    }
+
+    // None of the new edges on the ring crosses or runs into any other edge of it. Only the edges filed near them
+    // need looking at, along with the new edges themselves, which aren't filed yet.
+    fn is_clear(&self, edges: &[(u32, u32)]) -> bool
+    {
+        let xy = |v: u32| self.points[v as usize];
+        let mut near: Vec<u32> = edges.iter().map(|&(c, _)| c).collect();
+        for &(c, d) in edges
+        {
+            match self.edges.near(xy(c), xy(d))
+            {
+                Some(edges) => near.extend(edges),
+                None => { near = (0..self.next.len() as u32).collect(); break; },
+            }
+        }
+        near.sort_unstable();
+        near.dedup();
+
+        for a in near
+        {
+            let b = self.next[a as usize];
+            if b == u32::MAX
+                { continue; }
+            for &(c, d) in edges
+            {
+                if (a, b) == (c, d) || apart(xy(a), xy(b), xy(c), xy(d))
+                    { continue; }
+
+                // Edges that meet end to end only go wrong if they double back along each other
+                let shared = [(a, b, c, d), (b, a, c, d), (a, b, d, c), (b, a, d, c)].into_iter().find(|(x, _, z, _)| x == z);
+                let touching = match shared
+                {
+                    Some((x, y, _, w)) => T::orient2d(xy(x), xy(y), xy(w)) == 0.0 && (within(xy(x), xy(y), xy(w)) || within(xy(x), xy(w), xy(y))),
+                    None => touches(xy(a), xy(b), xy(c), xy(d)),
+                };
+                if touching
+                    { return false; }
+            }
+        }
+        true
+    }
+
+    // Swaps the triangles for ones filling the region, as long as it's simple and goes counter-clockwise. Those
+    // together with the triangles left over fill the new ring exactly once the new edges on it are clear.
+    fn replace(&mut self, old: &[usize], region: &[u32]) -> Option<Update>
+    {
+        let mut new = vec!();
+        if region.len() >= 3
+        {
+            let points: Vec<XY<T>> = region.iter().map(|&v| self.points[v as usize]).collect();
+            let area: f64 = (0..points.len()).map(|i|
+            {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                a.x.to_f64() * b.y.to_f64() - b.x.to_f64() * a.y.to_f64()
+            }).sum();
+            if area <= 0.0 || !validation::validate(&points).is_simple()
+                { return None; }
+            new = triangulator::calc_indexed(&points).into_iter().map(|triangle| triangle.map(|i| region[i as usize])).collect();
+        }
+
+        let mut old = old.to_vec();
+        old.sort_unstable_by(|a, b| b.cmp(a));
+        for t in old
+        {
+            for v in self.triangles[t]
+                { self.around[v as usize].retain(|&u| u != t); }
+            let last = self.triangles.len() - 1;
+            self.triangles.swap_remove(t);
+            if t != last
+            {
+                for v in self.triangles[t]
+                    { self.around[v as usize].iter_mut().filter(|u| **u == last).for_each(|u| *u = t); }
+            }
+        }
+
+        let count = new.len();
+        for triangle in new
+            { self.push_triangle(triangle); }
+        Some(Update::Local { triangles: count })
+    }
+
+    fn rebuild(&mut self) -> Result<Update, TriangulationError>
+    {
+        let ring = self.cycle();
+        let points: Vec<XY<T>> = ring.iter().map(|&v| self.points[v as usize]).collect();
+        let vertex = |index: usize| ring[index] as usize;
+        let triangles = triangulator::try_calc_indexed(&points).map_err(|error| match error
+        {
+            TriangulationError::TooFewPoints { count } => TriangulationError::TooFewPoints { count },
+            TriangulationError::DuplicateVertex { index, first_index } => TriangulationError::DuplicateVertex { index: vertex(index), first_index: vertex(first_index) },
+            TriangulationError::SelfIntersection { index, other_index } => TriangulationError::SelfIntersection { index: vertex(index), other_index: vertex(other_index) },
+            TriangulationError::NonFiniteCoordinate { index } => TriangulationError::NonFiniteCoordinate { index: vertex(index) },
+            TriangulationError::InternalInconsistency { index } => TriangulationError::InternalInconsistency { index: vertex(index) },
+            TriangulationError::NoSuchVertex { index } => TriangulationError::NoSuchVertex { index: vertex(index) },
+        })?;
+
+        self.set_triangles(triangles.into_iter().map(|triangle| triangle.map(|i| ring[i as usize])).collect());
+        Ok(Update::Rebuilt)
+    }
+
+    fn set_triangles(&mut self, triangles: Vec<[u32; 3]>)
+    {
+        self.triangles.clear();
+        self.around = vec!(vec!(); self.points.len());
+        for triangle in triangles
+            { self.push_triangle(triangle); }
+    }
+
+    fn push_triangle(&mut self, triangle: [u32; 3])
+    {
+        for v in triangle
+            { self.around[v as usize].push(self.triangles.len()); }
+        self.triangles.push(triangle);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn edits_on_missing_vertices_are_turned_down()
+    {
+        let square = vec!(XY::new(0.0, 0.0), XY::new(2.0, 0.0), XY::new(2.0, 2.0), XY::new(1.0, 3.0), XY::new(0.0, 2.0));
+        let mut triangulation = Triangulation::new(&square).unwrap();
+        assert!(triangulation.apply(Edit::Delete { vertex: 3 }).is_ok());
+        let triangles = triangulation.triangles().to_vec();
+
+        // Past the end of the points, and the one deleted above
+        let edits = [
+            (Edit::Move { vertex: 5, to: XY::new(1.0, 1.0) }, 5), (Edit::Delete { vertex: u32::MAX }, u32::MAX as usize), (Edit::Insert { after: 7, at: XY::new(1.0, -1.0) }, 7),
+            (Edit::Move { vertex: 3, to: XY::new(1.0, 1.0) }, 3), (Edit::Delete { vertex: 3 }, 3), (Edit::Insert { after: 3, at: XY::new(1.0, 3.0) }, 3),
+        ];
+        for (edit, index) in edits
+            { assert_eq!(triangulation.apply(edit), Err(TriangulationError::NoSuchVertex { index })); }
+
+        assert_eq!(triangulation.triangles(), triangles);
+        assert_eq!(triangulation.ring(), vec!(0, 1, 2, 4));
+    }
+
+    #[test]
+    fn moves_across_long_edges_are_turned_down()
+    {
+        // A comb of short teeth over one long edge, which is too long to file in the grid
+        let mut comb = vec!(XY::new(0, 0), XY::new(1000, 0));
+        comb.extend((0..=1000).rev().map(|x| XY::new(x, 10 + x % 2)));
+        let mut triangulation = Triangulation::new(&comb).unwrap();
+        assert_eq!(triangulation.edges.long, vec!(0));
+
+        // The grid has to turn up the long edge and the teeth on its own, whatever the triangles round them
+        let is_clear_moved = |v: u32, to: XY<i32>|
+        {
+            let mut moved = triangulation.clone();
+            moved.points[v as usize] = to;
+            moved.is_clear(&[(moved.previous[v as usize], v), (v, moved.next[v as usize])])
+        };
+        assert!(!is_clear_moved(502, XY::new(500, -5)));
+        assert!(!is_clear_moved(502, XY::new(500, 0)));
+        assert!(!is_clear_moved(600, XY::new(-1, 10)));
+        assert!(is_clear_moved(502, XY::new(500, 1)));
+
+        assert!(matches!(triangulation.apply(Edit::Move { vertex: 502, to: XY::new(500, -5) }), Err(TriangulationError::SelfIntersection { .. })));
+        assert!(matches!(triangulation.apply(Edit::Move { vertex: 502, to: XY::new(500, 0) }), Err(TriangulationError::SelfIntersection { .. })));
+        assert!(triangulation.apply(Edit::Move { vertex: 502, to: XY::new(500, 1) }).is_ok());
+
+        // And a tooth pulled across the ones next to it
+        assert!(matches!(triangulation.apply(Edit::Move { vertex: 600, to: XY::new(-1, 10) }), Err(TriangulationError::SelfIntersection { .. })));
+        let moved = triangulation.points()[600];
+        assert_eq!((moved.x, moved.y), (402, 10));
+    }
+}
@@ -0,0 +1,500 @@
+use crate::XY;
+use crate::triangulator::FillRule;
+use crate::predicates;
+use crate::scalar::Scalar;
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Intersection
+{
+    pub xy: XY<f64>, // Where the edges cross is worked out in f64 whatever the points are
+    pub edge_a: usize, // Edge from point edge_a to the point after it
+    pub edge_b: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SweepPoint
+{
+    x: f64,
+    y: f64,
+}
+
+impl SweepPoint
+{
+    // Adding zero turns -0.0 into 0.0, otherwise the two would sort apart and be two events in the same place
+    fn new<T: Scalar>(xy: XY<T>) -> SweepPoint { SweepPoint { x: xy.x.to_f64() + 0.0, y: xy.y.to_f64() + 0.0 } }
+    fn to_xy(self) -> XY<f64> { XY::new(self.x, self.y) }
+}
+
+impl Eq for SweepPoint {}
+
+impl PartialOrd for SweepPoint
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for SweepPoint
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.x.total_cmp(&other.x).then(self.y.total_cmp(&other.y))
+    }
+}
+
+// Where an event is. A vertex is tested exactly in its own coordinate type, a crossing only has a rounded place.
+#[derive(Debug, Copy, Clone)]
+enum At<T: Scalar>
+{
+    Vertex(XY<T>),
+    Crossing(SweepPoint),
+}
+
+#[derive(Debug, Clone)]
+struct Event<T: Scalar>
+{
+    at: At<T>,
+    starting: Vec<usize /*Segment Index*/>,
+    crossing: Vec<usize /*Segment Index*/>, // Segments found to cross here, which needn't go exactly through the rounded place
+}
+
+impl<T: Scalar> Event<T>
+{
+    fn new(at: At<T>) -> Event<T> { Event { at, starting: Vec::new(), crossing: Vec::new() } }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Segment<T: Scalar>
+{
+    left: SweepPoint,
+    right: SweepPoint,
+    ends: (XY<T> /*Left*/, XY<T> /*Right*/),
+}
+
+impl<T: Scalar> Segment<T>
+{
+    fn new(a: XY<T>, b: XY<T>) -> Segment<T>
+    {
+        let (left, right) = (SweepPoint::new(a), SweepPoint::new(b));
+        if left <= right { Segment { left, right, ends: (a, b) } } else { Segment { left: right, right: left, ends: (b, a) } }
+    }
+
+    // Positive when the event is above the segment and zero when it's on it
+    fn side_of(&self, at: &At<T>) -> f64
+    {
+        match at
+        {
+            At::Vertex(xy) => T::orient2d(self.ends.0, self.ends.1, *xy),
+            At::Crossing(p) => predicates::orient2d_f64([self.left.x, self.left.y], [self.right.x, self.right.y], [p.x, p.y]),
+        }
+    }
+}
+
+// Positive when b leaves the event above a. Both go on past it, so their right ends are round the same side of it
+// and this puts them in a consistent order.
+fn turn<T: Scalar>(at: &At<T>, a: &Segment<T>, b: &Segment<T>) -> f64
+{
+    match at
+    {
+        At::Vertex(xy) => T::orient2d(*xy, a.ends.1, b.ends.1),
+        At::Crossing(p) => predicates::orient2d_f64([p.x, p.y], [a.right.x, a.right.y], [b.right.x, b.right.y]),
+    }
+}
+
+// Every test of which side of a segment something is goes through orient2d, so only segments that really meet are
+// ever found touching. Coordinates are ordered as f64s though, so i64s past 2^53 can share an event.
+struct BentleyOttmann<'a, T: Scalar>
+{
+    segments: &'a [Segment<T>],
+    events: BTreeMap::<SweepPoint, Event<T>>,
+    status: Vec<usize /*Segment Index*/>,
+    crossings: Vec<(SweepPoint, Vec<usize /*Segment Index*/>)>,
+}
+
+impl<'a, T: Scalar> BentleyOttmann<'a, T>
+{
+    fn run(segments: &'a [Segment<T>]) -> Vec<(SweepPoint, Vec<usize>)>
+    {
+        let mut sweep = BentleyOttmann
+        {
+            segments,
+            events: BTreeMap::new(),
+            status: Vec::new(),
+            crossings: Vec::new(),
+        };
+
+        for (i, segment) in segments.iter().enumerate()
+        {
+            if segment.left == segment.right { continue; }
+            sweep.events.entry(segment.left).or_insert_with(|| Event::new(At::Vertex(segment.ends.0))).starting.push(i);
+            sweep.events.entry(segment.right).or_insert_with(|| Event::new(At::Vertex(segment.ends.1)));
+        }
+
+        while let Some((p, event)) = sweep.events.pop_first()
+            { sweep.handle_event(p, event); }
+
+        sweep.crossings
+    }
+
+    fn handle_event(&mut self, p: SweepPoint, event: Event<T>)
+    {
+        // Anything lying along a segment that crosses here crosses here too, even though the rounded place is off it
+        let segments = self.segments;
+        let along = |s: usize, c: usize| T::orient2d(segments[c].ends.0, segments[c].ends.1, segments[s].ends.0) == 0.0
+            && T::orient2d(segments[c].ends.0, segments[c].ends.1, segments[s].ends.1) == 0.0;
+        let passes_through = |s: usize| segments[s].side_of(&event.at) == 0.0 || event.crossing.iter().any(|&c| c == s || along(s, c));
+        let mut below = self.status.partition_point(|&s| !passes_through(s) && segments[s].side_of(&event.at) > 0.0);
+        let mut above = below;
+        while above < self.status.len() && passes_through(self.status[above])
+            { above += 1; }
+
+        // Where more lines than two cross at a point, its rounded place can be either side of the others, so the
+        // range is widened to every segment found crossing here
+        if event.crossing.iter().any(|c| !self.status[below..above].contains(c))
+        {
+            for (i, s) in self.status.iter().enumerate()
+            {
+                if event.crossing.contains(s)
+                    { below = below.min(i); above = above.max(i + 1); }
+            }
+        }
+
+        let mut touching = self.status[below..above].to_vec();
+        touching.extend(event.starting.iter().copied());
+        if touching.len() > 1
+            { self.crossings.push((p, touching)); }
+
+        // Segments passing through p carry on past it along with the ones starting here, re-ordered by where they go
+        let mut continuing: Vec<usize> = self.status.drain(below..above)
+            .filter(|s| segments[*s].right != p)
+            .collect();
+        continuing.extend(event.starting);
+        continuing.sort_by(|a, b|
+        {
+            let turn = turn(&event.at, &segments[*a], &segments[*b]);
+            if turn > 0.0 { Ordering::Less } else if turn < 0.0 { Ordering::Greater } else { Ordering::Equal }
+        });
+
+        let count = continuing.len();
+        self.status.splice(below..below, continuing);
+
+        if count == 0
+        {
+            if below > 0 && below < self.status.len()
+                { self.check_pair(self.status[below - 1], self.status[below], p); }
+        }
+        else
+        {
+            if below > 0
+                { self.check_pair(self.status[below - 1], self.status[below], p); }
+            if below + count < self.status.len()
+                { self.check_pair(self.status[below + count - 1], self.status[below + count], p); }
+        }
+    }
+
+    // Segments that only touch meet at an end of one of them, which is an event already. Only ones that cross
+    // properly, still the right way up before they do, need a new event where they cross.
+    fn check_pair(&mut self, lower: usize, upper: usize, p: SweepPoint)
+    {
+        let a = self.segments[lower]; let b = self.segments[upper];
+        let crosses = T::orient2d(a.ends.0, a.ends.1, b.ends.0) > 0.0 && T::orient2d(a.ends.0, a.ends.1, b.ends.1) < 0.0
+            && T::orient2d(b.ends.0, b.ends.1, a.ends.0) < 0.0 && T::orient2d(b.ends.0, b.ends.1, a.ends.1) > 0.0;
+        if !crosses { return; }
+
+        let da = (a.right.x - a.left.x, a.right.y - a.left.y);
+        let db = (b.right.x - b.left.x, b.right.y - b.left.y);
+        let offset = (b.left.x - a.left.x, b.left.y - a.left.y);
+        let t = ((offset.0 * db.1 - offset.1 * db.0) / (da.0 * db.1 - da.1 * db.0)).clamp(0.0, 1.0);
+        let mut q = SweepPoint { x: a.left.x + t * da.0, y: a.left.y + t * da.1 };
+
+        // Rounding can put the crossing no later than where the sweep has got to, it still has to come after
+        if q <= p
+            { q = SweepPoint { x: p.x, y: p.y.next_up() }; }
+        self.events.entry(q).or_insert_with(|| Event::new(At::Crossing(q))).crossing.extend([lower, upper]);
+    }
+}
+
+pub fn find_intersections<T: Scalar>(points: &[XY<T>]) -> Vec<Intersection>
+{
+    find_ring_intersections(&[points])
+}
+
+// Edges are numbered through all the rings in turn, edge i goes from point i to the next point round its ring
+pub fn find_ring_intersections<T: Scalar>(rings: &[&[XY<T>]]) -> Vec<Intersection>
+{
+    let mut points = Vec::with_capacity(rings.iter().map(|ring| ring.len()).sum());
+    let mut next = Vec::with_capacity(points.capacity());
+    for ring in rings
+    {
+        let start = points.len();
+        next.extend((0..ring.len()).map(|i| start + (i + 1) % ring.len()));
+        points.extend_from_slice(ring);
+    }
+    let segments: Vec<Segment<T>> = (0..points.len()).map(|i| Segment::new(points[i], points[next[i]])).collect();
+
+    let mut intersections = Vec::new();
+    for (p, touching) in BentleyOttmann::run(&segments)
+    {
+        for (i, &a) in touching.iter().enumerate()
+        {
+            for &b in &touching[i + 1..]
+            {
+                let (edge_a, edge_b) = (a.min(b), a.max(b));
+                let shared_vertex =
+                    if next[edge_a] == edge_b { Some(edge_b) }
+                    else if next[edge_b] == edge_a { Some(edge_a) }
+                    else { None };
+
+                // Neighbouring edges always meet at the vertex between them, that's not a crossing
+                if shared_vertex.is_some_and(|v| SweepPoint::new(points[v]) == p)
+                    { continue; }
+
+                intersections.push(Intersection { xy: p.to_xy(), edge_a, edge_b });
+            }
+        }
+    }
+
+    intersections
+}
+
+// Which way the edge crosses a ray going right from p, counting an end on the ray as just above it
+fn ray_crossing(a: XY, b: XY, p: [f64; 2]) -> i32
+{
+    let side_of_line = predicates::orient2d_f64([a.x as f64, a.y as f64], [b.x as f64, b.y as f64], p);
+    if a.y as f64 <= p[1] && b.y as f64 > p[1] && side_of_line > 0.0 { 1 }
+    else if a.y as f64 > p[1] && b.y as f64 <= p[1] && side_of_line < 0.0 { -1 }
+    else { 0 }
+}
+
+pub(crate) fn winding_number(ring: &[XY], p: XY) -> i32
+{
+    (0..ring.len()).map(|i| ray_crossing(ring[i], ring[(i + 1) % ring.len()], [p.x as f64, p.y as f64])).sum()
+}
+
+// Splits the rings wherever they cross or touch and rebuilds them into simple rings around just the parts the
+// fill rule fills. Outer boundaries come out counter-clockwise and holes clockwise.
+pub fn untangle_rings(rings: &[&[XY]], fill_rule: FillRule) -> Vec<Vec<XY>>
+{
+    untangle_rings_by(rings, |winding_number| fill_rule.fills(winding_number))
+}
+
+// The same with any rule for which winding numbers are filled
+pub(crate) fn untangle_rings_by(rings: &[&[XY]], fills: impl Fn(i32) -> bool) -> Vec<Vec<XY>>
+{
+    let rings: Vec<&[XY]> = rings.iter().copied().filter(|ring| ring.len() >= 3).collect();
+    let intersections = find_ring_intersections(&rings);
+
+    let key = |xy: &XY| ((xy.x + 0.0).to_bits(), (xy.y + 0.0).to_bits());
+
+    let mut points = Vec::new();
+    let mut next_point = Vec::new();
+    for ring in &rings
+    {
+        let start = points.len();
+        next_point.extend((0..ring.len()).map(|i| start + (i + 1) % ring.len()));
+        points.extend_from_slice(ring);
+    }
+
+    let mut edge_crossings = vec!(Vec::<(f32, XY)>::new(); points.len());
+    for intersection in &intersections
+    {
+        let xy = XY::new(intersection.xy.x as f32, intersection.xy.y as f32);
+        for edge in [intersection.edge_a, intersection.edge_b]
+        {
+            let a = points[edge]; let b = points[next_point[edge]];
+            if key(&xy) == key(&a) || key(&xy) == key(&b)
+                { continue; }
+            let t = ((xy.x - a.x) * (b.x - a.x) + (xy.y - a.y) * (b.y - a.y)) / ((b.x - a.x).powi(2) + (b.y - a.y).powi(2));
+            edge_crossings[edge].push((t, xy));
+        }
+    }
+
+    let mut walk = Vec::with_capacity(points.len() + intersections.len() * 2);
+    let mut next = Vec::with_capacity(walk.capacity());
+    let mut first_edge = 0;
+    for ring in &rings
+    {
+        let start = walk.len();
+        for edge in first_edge..first_edge + ring.len()
+        {
+            walk.push(points[edge]);
+            let crossings = &mut edge_crossings[edge];
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            crossings.dedup_by(|a, b| key(&a.1) == key(&b.1));
+            walk.extend(crossings.iter().map(|(_, xy)| *xy));
+        }
+        next.extend((start..walk.len()).map(|i| if i + 1 == walk.len() { start } else { i + 1 }));
+        first_edge += ring.len();
+    }
+
+    // Edges that overlap have been split at each other's ends, so the same piece can be in the walk several times.
+    // Each piece is kept once, along with how many more times the walk goes along it forwards than backwards. They're
+    // kept in order so the same rings always come out the same way.
+    let mut pieces = BTreeMap::<((u32, u32), (u32, u32)), (XY, XY, i32 /*Net Count*/)>::new();
+    for (i, &from) in walk.iter().enumerate()
+    {
+        let to = walk[next[i]];
+        if key(&from) == key(&to)
+            { continue; }
+
+        let is_forward = (from.x, from.y) < (to.x, to.y);
+        let (a, b) = if is_forward { (from, to) } else { (to, from) };
+        pieces.entry((key(&a), key(&b))).or_insert((a, b, 0)).2 += if is_forward { 1 } else { -1 };
+    }
+    let pieces: Vec<(XY, XY, i32)> = pieces.into_values().filter(|piece| piece.2 != 0).collect();
+
+    // Only keep the pieces that separate a part the fill rule fills from a part it doesn't, the way round that has
+    // the filled part on the left
+    let mut kept = Vec::<(XY, XY)>::new();
+    for (i, &(a, b, count)) in pieces.iter().enumerate()
+    {
+        // Nothing else goes through the middle of a piece. Counting how many times everything else crosses a ray to
+        // the right of it gives the winding number just to the right of it, or just above if it's flat.
+        let middle = [(a.x as f64 + b.x as f64) / 2.0, (a.y as f64 + b.y as f64) / 2.0];
+        let winding_others: i32 = pieces.iter().enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, other)| ray_crossing(other.0, other.1, middle) * other.2)
+            .sum();
+
+        // Pieces go left to right, so going up the right is their right and going down or flat it's their left
+        let (winding_left, winding_right) =
+            if b.y > a.y { (winding_others + count, winding_others) }
+            else { (winding_others, winding_others - count) };
+
+        if fills(winding_left) != fills(winding_right)
+            { kept.push(if fills(winding_left) { (a, b) } else { (b, a) }); }
+    }
+
+    link_edges(&kept)
+}
+
+// Joins the edges up into rings. Where rings touch, each edge coming into the point carries on along the first edge
+// going out clockwise from it. Every corner then has its filled side clear of other edges, so rings can be pulled
+// apart there without crossing.
+fn link_edges(edges: &[(XY, XY)]) -> Vec<Vec<XY>>
+{
+    let key = |xy: &XY| ((xy.x + 0.0).to_bits(), (xy.y + 0.0).to_bits());
+
+    let mut next = vec!(usize::MAX; edges.len());
+    let mut outgoing = HashMap::<(u32, u32), Vec<usize /*Edge Index*/>>::new();
+    for (e, edge) in edges.iter().enumerate()
+        { outgoing.entry(key(&edge.0)).or_default().push(e); }
+
+    let mut taken = vec!(false; edges.len());
+    for e in 0..edges.len()
+    {
+        let (from, at) = edges[e];
+        let Some(candidates) = outgoing.get(&key(&at))
+            else { continue; };
+
+        let back = (from.y - at.y).atan2(from.x - at.x);
+        let clockwise_turn = |out: &usize|
+        {
+            let to = edges[*out].1;
+            let turn = (back - (to.y - at.y).atan2(to.x - at.x)).rem_euclid(std::f32::consts::TAU);
+            if turn == 0.0 { std::f32::consts::TAU } else { turn }
+        };
+        if let Some(&out) = candidates.iter().filter(|out| !taken[**out]).min_by(|a, b| clockwise_turn(a).total_cmp(&clockwise_turn(b)))
+        {
+            taken[out] = true;
+            next[e] = out;
+        }
+    }
+
+    let mut reconnected = Vec::new();
+    let mut visited = vec!(false; edges.len());
+    for start in 0..edges.len()
+    {
+        let mut ring = Vec::new();
+        let mut e = start;
+        while e != usize::MAX && !visited[e]
+        {
+            visited[e] = true;
+            ring.push(edges[e].0);
+            e = next[e];
+        }
+        if ring.len() >= 3
+            { reconnected.push(ring); }
+    }
+
+    reconnected
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator::{self, TriangulationError};
+    use crate::validation;
+
+    #[test]
+    fn negative_zero_is_the_same_place_as_zero()
+    {
+        let ring = vec!(XY::new(0.0, 0.0), XY::new(2.0, 0.0), XY::new(2.0, 2.0), XY::new(-0.0, 2.0), XY::new(-0.0, 1.0));
+        assert!(find_intersections(&ring).is_empty());
+        assert_eq!(triangulator::try_calc(&ring).map(|triangles| triangles.len()), Ok(3));
+
+        let square = vec!(XY::new(0.0, 0.0), XY::new(2.0, 0.0), XY::new(2.0, 2.0), XY::new(0.0, 2.0));
+        let above = vec!(XY::new(-0.0, 1.0), XY::new(1.0, 1.0), XY::new(1.0, 3.0), XY::new(-0.0, 3.0));
+        let triangles = triangulator::try_calc_filled(&[square, above], FillRule::NonZero).unwrap();
+        let area: f32 = triangles.iter().map(|(a, b, c)| ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) / 2.0).sum();
+        assert_eq!(area, 5.0);
+    }
+
+    #[test]
+    fn untangling_comes_out_the_same_every_time()
+    {
+        let star: Vec<XY> = (0..7).map(|i| { let a = i as f32 * 3.0 * std::f32::consts::TAU / 7.0; XY::new(a.cos(), a.sin()) }).collect();
+        let square = vec!(XY::new(-0.5, -0.5), XY::new(1.5, -0.5), XY::new(1.5, 0.5), XY::new(-0.5, 0.5));
+        let untangle = || untangle_rings(&[&star, &square], FillRule::NonZero).iter()
+            .map(|ring| ring.iter().map(|xy| (xy.x, xy.y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let first = untangle();
+        assert!(!first.is_empty());
+        for _ in 0..20
+            { assert_eq!(untangle(), first); }
+    }
+
+    #[test]
+    fn near_misses_are_not_crossings()
+    {
+        let (far, near) = (1_000_000_000, 999_999_999);
+        let integer = vec!(XY::new(-far, -far), XY::new(far, -far), XY::new(far, far), XY::new(0, -near), XY::new(-far, far));
+        assert!(find_intersections(&integer).is_empty());
+        assert!(validation::validate(&integer).is_simple());
+        assert_eq!(triangulator::try_calc(&integer).map(|triangles| triangles.len()), Ok(3));
+
+        let touching = vec!(XY::new(-far, -far), XY::new(far, -far), XY::new(far, far), XY::new(0, -far), XY::new(-far, far));
+        assert_eq!(triangulator::try_calc_indexed(&touching), Err(TriangulationError::SelfIntersection { index: 0, other_index: 3 }));
+
+        // A millimetre gap in UTM metres
+        let (east, north) = (500_000.0, 4_000_000.0);
+        let utm = vec!(XY::new(east, north), XY::new(east + 100.0, north), XY::new(east + 100.0, north + 100.0),
+            XY::new(east + 50.0, north + 0.001), XY::new(east, north + 100.0));
+        assert!(find_intersections(&utm).is_empty());
+        assert!(validation::validate(&utm).is_simple());
+        assert_eq!(triangulator::try_calc(&utm).map(|triangles| triangles.len()), Ok(3));
+    }
+
+    #[test]
+    fn crossings_are_where_the_edges_cross()
+    {
+        // Far enough out that f32 would put the crossing a fraction of a metre away
+        let (east, north) = (500_000.125, 4_000_000.125);
+        let bowtie = vec!(XY::new(east, north), XY::new(east + 100.0, north + 100.0), XY::new(east + 100.0, north), XY::new(east, north + 100.0));
+        let crossings = find_intersections(&bowtie);
+        assert_eq!(crossings.len(), 1);
+        assert_eq!((crossings[0].xy.x, crossings[0].xy.y), (east + 50.0, north + 50.0));
+        match validation::validate(&bowtie).issues[..]
+        {
+            [validation::Issue::SelfIntersection { xy, .. }] => assert_eq!((xy.x, xy.y), (east + 50.0, north + 50.0)),
+            ref issues => panic!("{:?}", issues),
+        }
+
+        let far = 1_i64 << 60;
+        let repeated = vec!(XY::new(far + 1, 0), XY::new(far + 2, 0), XY::new(far + 2, 1), XY::new(far + 1, 0), XY::new(far + 1, 1));
+        assert!(validation::validate(&repeated).issues.iter()
+            .any(|issue| matches!(issue, validation::Issue::DuplicateVertex { xy, .. } if xy.x == far + 1 && xy.y == 0)));
+    }
+}
@@ -0,0 +1,32 @@
+pub mod triangulator;
+pub mod intersections;
+pub mod predicates;
+pub mod scalar;
+pub mod delaunay;
+pub mod validation;
+pub mod convex;
+pub mod strips;
+pub mod curves;
+pub mod stroke;
+pub mod booleans;
+pub mod offset;
+pub mod simplify;
+pub mod incremental;
+pub mod batch;
+
+#[derive(Debug, Copy, Clone)]
+pub struct XY<T = f32> 
+{ 
+    pub x: T, 
+    pub y: T 
+}
+
+impl<T> XY<T> 
+{
+    pub fn new(x: T, y: T) -> XY<T> { XY {x, y} }
+}
+
+impl XY 
+{
+    pub fn init() -> XY { XY::new(0.0, 0.0) }
+}
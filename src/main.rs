@@ -1,3 +1,5 @@
+#[cfg(test)] mod scratch;
+#[cfg(test)] mod scratch_shapes;
 use winit::application::ApplicationHandler;
 use winit::event::{KeyEvent, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
@@ -8,21 +10,8 @@ use wgpu::util::DeviceExt;
 
 use std::sync::Arc;
 
-mod triangulator;
 mod data;
-
-#[derive(Debug, Copy, Clone)]
-pub struct XY 
-{ 
-    pub x: f32, 
-    pub y: f32 
-}
-
-impl XY 
-{
-    pub fn new(x: f32, y: f32) -> XY { XY {x, y} }
-    pub fn init() -> XY { XY::new(0.0, 0.0) }
-}
+mod bench;
 
 const CLEAR_COLOUR: wgpu::Color = wgpu::Color { r: 0.1, g: 0.1, b: 0.2, a: 1.0 };
 
@@ -69,11 +58,16 @@ struct State
     config: wgpu::SurfaceConfiguration,
     is_surface_configured: bool,
     render_pipeline: wgpu::RenderPipeline,
+    strip_pipeline: wgpu::RenderPipeline,
     vertex_buffer: wgpu::Buffer,
     num_vertices: u32,
+    index_buffer: wgpu::Buffer,
+    num_indices: u32,
     window: Arc<Window>,
     shapes_list: Vec<Vec<Vertex>>,
+    strips_list: Vec<(Vec<Vertex>, Vec<u32>)>,
     shape_num: u32,
+    is_strip_mode: bool,
 }
 
 impl State 
@@ -166,7 +160,7 @@ impl State
         {
             label: Some("Render Pipeline"),
             layout: Some(&render_pipeline_layout),
-            vertex: vertex,
+            vertex,
             fragment: Some(fragment),
             primitive: wgpu::PrimitiveState 
             {
@@ -189,8 +183,23 @@ impl State
             cache: None,
         };
         let render_pipeline = device.create_render_pipeline(pipeline_descriptor);
+
+        // Strips come with restart indices between them, which need the u32 index format
+        let strip_pipeline_descriptor = &wgpu::RenderPipelineDescriptor
+        {
+            label: Some("Strip Render Pipeline"),
+            primitive: wgpu::PrimitiveState
+            {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                strip_index_format: Some(wgpu::IndexFormat::Uint32),
+                ..pipeline_descriptor.primitive
+            },
+            ..pipeline_descriptor.clone()
+        };
+        let strip_pipeline = device.create_render_pipeline(strip_pipeline_descriptor);
         
         let shapes_list = data::make_shapes();
+        let strips_list = data::make_strips(&shapes_list);
         let shape_num = 0;
 
         let vertex_buffer_init_descriptor = wgpu::util::BufferInitDescriptor 
@@ -202,6 +211,15 @@ impl State
         let vertex_buffer = device.create_buffer_init(&vertex_buffer_init_descriptor);
         let num_vertices = shapes_list[0].len() as u32;
 
+        let index_buffer_init_descriptor = wgpu::util::BufferInitDescriptor 
+        {
+            label: Some("Index Buffer"),
+            contents: bytemuck::cast_slice(&strips_list[0].1),
+            usage: wgpu::BufferUsages::INDEX,
+        };
+        let index_buffer = device.create_buffer_init(&index_buffer_init_descriptor);
+        let num_indices = strips_list[0].1.len() as u32;
+
         Self
         {
             surface,
@@ -210,11 +228,16 @@ impl State
             config,
             is_surface_configured: false,
             render_pipeline,
+            strip_pipeline,
             vertex_buffer,
             num_vertices,
+            index_buffer,
+            num_indices,
             window,
             shapes_list, 
-            shape_num
+            strips_list,
+            shape_num,
+            is_strip_mode: false
         }
     }
 
@@ -231,20 +254,41 @@ impl State
     fn change_vertices(&mut self)
     {
         self.shape_num = (self.shape_num + 1) % self.shapes_list.len() as u32;
+        self.load_vertices();
+    }
 
-        let verts = &self.shapes_list[self.shape_num as usize];
+    fn toggle_strips(&mut self)
+    {
+        self.is_strip_mode = !self.is_strip_mode;
+        self.load_vertices();
+    }
+
+    fn load_vertices(&mut self)
+    {
+        let (verts, indices) = 
+            if self.is_strip_mode { (&self.strips_list[self.shape_num as usize].0, &self.strips_list[self.shape_num as usize].1[..]) }
+            else { (&self.shapes_list[self.shape_num as usize], &[][..]) };
 
         let vertex_buffer_init_descriptor = wgpu::util::BufferInitDescriptor 
         {
             label: Some("Vertex Buffer"),
-            contents: bytemuck::cast_slice(&verts),
+            contents: bytemuck::cast_slice(verts),
             usage: wgpu::BufferUsages::VERTEX,
         };
         self.vertex_buffer = self.device.create_buffer_init(&vertex_buffer_init_descriptor);
         self.num_vertices = verts.len() as u32;
+
+        let index_buffer_init_descriptor = wgpu::util::BufferInitDescriptor 
+        {
+            label: Some("Index Buffer"),
+            contents: bytemuck::cast_slice(indices),
+            usage: wgpu::BufferUsages::INDEX,
+        };
+        self.index_buffer = self.device.create_buffer_init(&index_buffer_init_descriptor);
+        self.num_indices = indices.len() as u32;
     }
 
-    fn render(&mut self) -> ()
+    fn render(&mut self)
     {
         self.window.request_redraw();
 
@@ -274,9 +318,19 @@ impl State
         };
         let mut render_pass = encoder.begin_render_pass(&render_pass_descriptor);
 
-        render_pass.set_pipeline(&self.render_pipeline);
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        render_pass.draw(0..self.num_vertices, 0..1);
+        if self.is_strip_mode
+        {
+            render_pass.set_pipeline(&self.strip_pipeline);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+        }
+        else
+        {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.draw(0..self.num_vertices, 0..1);
+        }
 
         drop(render_pass);
 
@@ -290,6 +344,7 @@ impl State
         {
             (KeyCode::Escape, true) => event_loop.exit(),
             (KeyCode::Space, true) => self.change_vertices(),
+            (KeyCode::KeyS, true) => self.toggle_strips(),
             _ => {}
         }
     }
@@ -339,6 +394,9 @@ fn main()
 {
     env_logger::init();
 
+    if std::env::args().any(|arg| arg == "--bench")
+        { return bench::run(); }
+
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
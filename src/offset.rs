@@ -0,0 +1,209 @@
+use crate::XY;
+use crate::booleans;
+use crate::intersections;
+use crate::triangulator::FillRule;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum OffsetJoin { Miter, Round, Square }
+
+fn add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) { (a.0 + b.0, a.1 + b.1) }
+fn scale(a: (f64, f64), s: f64) -> (f64, f64) { (a.0 * s, a.1 * s) }
+fn dot(a: (f64, f64), b: (f64, f64)) -> f64 { a.0 * b.0 + a.1 * b.1 }
+fn cross(a: (f64, f64), b: (f64, f64)) -> f64 { a.0 * b.1 - a.1 * b.0 }
+fn to_f64(xy: XY) -> (f64, f64) { (xy.x as f64, xy.y as f64) }
+fn to_xy((x, y): (f64, f64)) -> XY { XY::new(x as f32, y as f32) }
+
+// Grows the filled area by distance all round, or shrinks it when distance is negative. The rings are filled by the
+// non-zero rule and the result comes back the same way as from booleans::calc, so a polygon that shrinks apart gives
+// one outer ring for each piece and one that grows round a gap gets a hole. Miters reaching out further than
+// miter_limit times the distance are bevelled, as in SVG, and round joins stay within tolerance of a true circle.
+pub fn offset(rings: &[Vec<XY>], distance: f32, join: OffsetJoin, miter_limit: f32, tolerance: f32) -> Vec<(Vec<XY>, Vec<Vec<XY>>)>
+{
+    let rings: Vec<&[XY]> = rings.iter().map(|ring| ring.as_slice()).collect();
+    let untangled = intersections::untangle_rings(&rings, FillRule::NonZero);
+    if distance == 0.0 || !distance.is_finite()
+        { return booleans::group_holes(untangled); }
+
+    // Outer rings go counter-clockwise and holes clockwise, so outwards from the fill is always to the right. Each
+    // ring moved out overlaps itself wherever it has to go round less than before, and those parts wind backwards.
+    // That can only be sorted out one piece at a time, an outer ring with its holes, or the backwards winding of one
+    // piece would cancel out the fill of another piece it grows into.
+    let mut moved_pieces = vec!();
+    for (outer, holes) in booleans::group_holes(untangled)
+    {
+        let moved: Vec<Vec<XY>> = std::iter::once(&outer).chain(&holes)
+            .map(|ring| offset_ring(ring, distance as f64, join, miter_limit as f64, tolerance as f64))
+            .collect();
+        let moved: Vec<&[XY]> = moved.iter().map(|ring| ring.as_slice()).collect();
+        moved_pieces.extend(intersections::untangle_rings(&moved, FillRule::Positive));
+    }
+    let moved_pieces: Vec<&[XY]> = moved_pieces.iter().map(|ring| ring.as_slice()).collect();
+    booleans::group_holes(intersections::untangle_rings(&moved_pieces, FillRule::NonZero))
+}
+
+fn offset_ring(ring: &[XY], distance: f64, join: OffsetJoin, miter_limit: f64, tolerance: f64) -> Vec<XY>
+{
+    let count = ring.len();
+    let directions: Vec<(f64, f64)> = (0..count).map(|i|
+    {
+        let (from, to) = (to_f64(ring[i]), to_f64(ring[(i + 1) % count]));
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        scale((dx, dy), 1.0 / dx.hypot(dy))
+    }).collect();
+
+    let mut points = vec!();
+    for i in 0..count
+    {
+        let p = to_f64(ring[i]);
+        let (d1, d2) = (directions[(i + count - 1) % count], directions[i]);
+        let (n1, n2) = ((d1.1, -d1.0), (d2.1, -d2.0));
+        let (a, b) = (add(p, scale(n1, distance)), add(p, scale(n2, distance)));
+        let (sin, cos) = (cross(n1, n2), dot(n1, n2));
+
+        if sin == 0.0 && cos > 0.0
+            { points.push(a); }
+        else if sin * distance < 0.0
+        {
+            // The two edges overlap here, going back through the corner keeps the overlap winding the wrong way
+            points.extend([a, p, b]);
+        }
+        else
+        {
+            points.push(a);
+            match join
+            {
+                OffsetJoin::Miter =>
+                {
+                    // The miter reaches out 1 / cos(half the turn) times the distance, along the line between the normals
+                    let cos_half = ((1.0 + cos) / 2.0).sqrt();
+                    if cos_half * miter_limit >= 1.0
+                        { points.push(add(p, scale(add(n1, n2), distance / (1.0 + cos)))); }
+                },
+                OffsetJoin::Square =>
+                {
+                    // Both edges carry on until they meet the line square to the corner, the distance out from it
+                    let middle = add(n1, n2);
+                    let outwards = if middle.0.hypot(middle.1) > 0.0 { scale(middle, distance.signum() / middle.0.hypot(middle.1)) } else { d1 };
+                    let along = (distance.abs() - distance * dot(n1, outwards)) / dot(d1, outwards);
+                    points.push(add(a, scale(d1, along)));
+                    points.push(add(b, scale(d2, -along)));
+                },
+                OffsetJoin::Round =>
+                {
+                    // Round the short way, which is the way the corner turns on the side it's moving out to
+                    let angle = distance.signum() * cos.clamp(-1.0, 1.0).acos();
+                    let step = 2.0 * (1.0 - (tolerance / distance.abs()).min(1.0)).acos();
+                    let steps = if step > 0.0 { ((angle.abs() / step).ceil() as usize).clamp(1, 1024) } else { 1024 };
+                    for k in 1..steps
+                    {
+                        let (s, c) = (angle * k as f64 / steps as f64).sin_cos();
+                        points.push(add(p, scale((n1.0 * c - n1.1 * s, n1.0 * s + n1.1 * c), distance)));
+                    }
+                },
+            }
+            points.push(b);
+        }
+    }
+
+    points.into_iter().map(to_xy).collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator;
+
+    fn square(size: f32) -> Vec<XY>
+    {
+        vec!(XY::new(0.0, 0.0), XY::new(size, 0.0), XY::new(size, size), XY::new(0.0, size))
+    }
+
+    // Every piece has to triangulate as it comes, the area is what the triangles cover
+    fn triangulated_area(groups: &[(Vec<XY>, Vec<Vec<XY>>)]) -> f64
+    {
+        groups.iter()
+            .map(|(outer, holes)| triangulator::try_calc_with_holes(outer, holes).unwrap())
+            .map(|triangles| triangles.iter().map(|(a, b, c)| ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) as f64 / 2.0).sum::<f64>())
+            .sum()
+    }
+
+    fn is_filled(groups: &[(Vec<XY>, Vec<Vec<XY>>)], p: XY) -> bool
+    {
+        groups.iter().any(|(outer, holes)| intersections::winding_number(outer, p) != 0 && holes.iter().all(|hole| intersections::winding_number(hole, p) == 0))
+    }
+
+    fn distance_to(rings: &[Vec<XY>], p: XY) -> f64
+    {
+        rings.iter().flat_map(|ring| (0..ring.len()).map(move |i| (to_f64(ring[i]), to_f64(ring[(i + 1) % ring.len()]))))
+            .map(|(a, b)|
+            {
+                let (d, w) = ((b.0 - a.0, b.1 - a.1), (p.x as f64 - a.0, p.y as f64 - a.1));
+                let t = (dot(w, d) / dot(d, d)).clamp(0.0, 1.0);
+                (w.0 - t * d.0).hypot(w.1 - t * d.1)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn squares_grow_and_shrink_by_the_distance()
+    {
+        let rings = vec!(square(2.0));
+        assert!((triangulated_area(&offset(&rings, 0.5, OffsetJoin::Miter, 4.0, 0.001)) - 9.0).abs() < 1e-5);
+        assert!((triangulated_area(&offset(&rings, 0.5, OffsetJoin::Square, 4.0, 0.001)) - (9.0 - 4.0 * (1.5 - 2.0f64.sqrt()) * 0.25 / 0.5)).abs() < 0.05);
+        let round = triangulated_area(&offset(&rings, 0.5, OffsetJoin::Round, 4.0, 0.001));
+        assert!(round < 4.0 + 4.0 + std::f64::consts::PI * 0.25 && round > 4.0 + 4.0 + std::f64::consts::PI * 0.25 - 0.01, "{}", round);
+        assert!((triangulated_area(&offset(&rings, -0.5, OffsetJoin::Round, 4.0, 0.001)) - 1.0).abs() < 1e-5);
+        assert!(offset(&rings, -1.1, OffsetJoin::Miter, 4.0, 0.001).is_empty());
+    }
+
+    #[test]
+    fn shapes_shrink_apart_and_grow_round_gaps()
+    {
+        let dumbbell = vec!(vec!(XY::new(0.0, 0.0), XY::new(2.0, 0.0), XY::new(2.0, 0.9), XY::new(3.0, 0.9), XY::new(3.0, 0.0), XY::new(5.0, 0.0),
+            XY::new(5.0, 2.0), XY::new(3.0, 2.0), XY::new(3.0, 1.1), XY::new(2.0, 1.1), XY::new(2.0, 2.0), XY::new(0.0, 2.0)));
+        let groups = offset(&dumbbell, -0.3, OffsetJoin::Miter, 4.0, 0.001);
+        assert_eq!(groups.len(), 2);
+        assert!((triangulated_area(&groups) - 2.0 * 1.4 * 1.4).abs() < 1e-4);
+
+        let c = vec!(vec!(XY::new(0.0, 0.0), XY::new(3.0, 0.0), XY::new(3.0, 3.0), XY::new(1.6, 3.0), XY::new(1.6, 2.0), XY::new(2.0, 2.0),
+            XY::new(2.0, 1.0), XY::new(1.0, 1.0), XY::new(1.0, 2.0), XY::new(1.4, 2.0), XY::new(1.4, 3.0), XY::new(0.0, 3.0)));
+        let groups = offset(&c, 0.15, OffsetJoin::Miter, 4.0, 0.001);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1.len(), 1);
+        assert!((triangulated_area(&groups) - (3.3 * 3.3 - 0.7 * 0.7)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn tangled_rings_grow_all_round()
+    {
+        // Untangled, this is seven rings touching at their corners
+        let tangled = vec!(vec!(XY::new(-0.8726405, -0.32054806), XY::new(0.79451287, 0.29019558), XY::new(-0.2458629, -0.074368834),
+            XY::new(0.18869042, -0.85079134), XY::new(-0.2755683, 0.7939365), XY::new(-0.37581474, -0.818968), XY::new(-0.713142, 0.44554782),
+            XY::new(0.39930582, -0.25395942), XY::new(0.08230877, -0.12469679)));
+        let rings: Vec<&[XY]> = tangled.iter().map(|ring| ring.as_slice()).collect();
+        let filled = intersections::untangle_rings(&rings, FillRule::NonZero);
+
+        // Everything within the distance has to be covered, and nothing further out than each join can reach. Miters
+        // are kept from being bevelled so they cover all of it.
+        let distance = 0.16;
+        for (join, reach) in [(OffsetJoin::Miter, f64::INFINITY), (OffsetJoin::Square, 2.0f64.sqrt()), (OffsetJoin::Round, 1.0)]
+        {
+            let groups = offset(&tangled, distance, join, 1000.0, 0.001);
+            triangulated_area(&groups);
+            for i in 0..60
+            {
+                for j in 0..60
+                {
+                    let p = XY::new(-1.2 + 2.4 * (i as f32 + 0.37) / 60.0, -1.2 + 2.4 * (j as f32 + 0.61) / 60.0);
+                    let is_inside = filled.iter().map(|ring| intersections::winding_number(ring, p)).sum::<i32>() != 0;
+                    let away = distance_to(&filled, p);
+                    if is_inside || away < distance as f64 - 0.005
+                        { assert!(is_filled(&groups, p), "{:?} {:?}", join, p); }
+                    else if away > distance as f64 * reach + 0.005
+                        { assert!(!is_filled(&groups, p), "{:?} {:?}", join, p); }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::XY;
+
+// Shewchuk's adaptive predicates. The sign is worked out in plain f64 first, and only when that's too close to
+// call is the whole determinant redone exactly, as a sum of non-overlapping f64 parts (an expansion).
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+fn two_sum(a: f64, b: f64) -> (f64, f64)
+{
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64)
+{
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64>
+{
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e
+    {
+        let (sum, error) = two_sum(q, component);
+        q = sum;
+        if error != 0.0
+            { h.push(error); }
+    }
+    if q != 0.0
+        { h.push(q); }
+    h
+}
+
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64>
+{
+    f.iter().fold(e.to_vec(), |sum, component| grow_expansion(&sum, *component))
+}
+
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64>
+{
+    let mut h = Vec::with_capacity(e.len() * 2);
+    let Some((&first, rest)) = e.split_first()
+        else { return h; };
+
+    let (mut q, error) = two_product(first, b);
+    if error != 0.0
+        { h.push(error); }
+    for &component in rest
+    {
+        let (product, product_error) = two_product(component, b);
+        let (sum, error) = two_sum(q, product_error);
+        if error != 0.0
+            { h.push(error); }
+        let (sum, error) = two_sum(product, sum);
+        if error != 0.0
+            { h.push(error); }
+        q = sum;
+    }
+    if q != 0.0
+        { h.push(q); }
+    h
+}
+
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64>
+{
+    f.iter().fold(Vec::new(), |sum, component| expansion_sum(&sum, &scale_expansion(e, *component)))
+}
+
+fn negate(e: &[f64]) -> Vec<f64>
+{
+    e.iter().map(|component| -component).collect()
+}
+
+fn difference(a: f64, b: f64) -> Vec<f64>
+{
+    grow_expansion(&[a], -b)
+}
+
+// The biggest part of an expansion comes last and outweighs the rest, so it has the sign of the whole
+fn most_significant(e: &[f64]) -> f64
+{
+    e.last().copied().unwrap_or(0.0)
+}
+
+// Positive when a, b and c go round counter-clockwise, negative when clockwise and zero only if they're in a line
+pub fn orient2d(a: XY, b: XY, c: XY) -> f64
+{
+    orient2d_f64([a.x as f64, a.y as f64], [b.x as f64, b.y as f64], [c.x as f64, c.y as f64])
+}
+
+pub fn orient2d_f64(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64
+{
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = left - right;
+
+    let det_sum =
+        if left > 0.0 && right > 0.0 { left + right }
+        else if left < 0.0 && right < 0.0 { -left - right }
+        else { return det; };
+
+    if det.abs() >= ORIENT_ERROR_BOUND * det_sum
+        { return det; }
+
+    // The c.x * c.y parts cancel out, what's left is six exact products
+    let mut exact = Vec::new();
+    for (x, y, sign) in [(a[0], b[1], 1.0), (a[0], c[1], -1.0), (c[0], b[1], -1.0), (a[1], b[0], -1.0), (a[1], c[0], 1.0), (c[1], b[0], 1.0)]
+    {
+        let (product, error) = two_product(x * sign, y);
+        exact = expansion_sum(&exact, &[error, product]);
+    }
+    most_significant(&exact)
+}
+
+// Positive when d is inside the circle through a, b and c, which have to go round counter-clockwise
+pub fn incircle(a: XY, b: XY, c: XY, d: XY) -> f64
+{
+    incircle_f64([a.x as f64, a.y as f64], [b.x as f64, b.y as f64], [c.x as f64, c.y as f64], [d.x as f64, d.y as f64])
+}
+
+pub fn incircle_f64(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64
+{
+    let (adx, ady) = (a[0] - d[0], a[1] - d[1]);
+    let (bdx, bdy) = (b[0] - d[0], b[1] - d[1]);
+    let (cdx, cdy) = (c[0] - d[0], c[1] - d[1]);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+
+    let det = a_lift * (bdxcdy - cdxbdy) + b_lift * (cdxady - adxcdy) + c_lift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * a_lift + (cdxady.abs() + adxcdy.abs()) * b_lift + (adxbdy.abs() + bdxady.abs()) * c_lift;
+    if det.abs() > INCIRCLE_ERROR_BOUND * permanent
+        { return det; }
+
+    let (adx, ady) = (difference(a[0], d[0]), difference(a[1], d[1]));
+    let (bdx, bdy) = (difference(b[0], d[0]), difference(b[1], d[1]));
+    let (cdx, cdy) = (difference(c[0], d[0]), difference(c[1], d[1]));
+
+    let lift = |x: &[f64], y: &[f64]| expansion_sum(&expansion_product(x, x), &expansion_product(y, y));
+    let minor = |x1: &[f64], y2: &[f64], x2: &[f64], y1: &[f64]| expansion_sum(&expansion_product(x1, y2), &negate(&expansion_product(x2, y1)));
+
+    let a_term = expansion_product(&lift(&adx, &ady), &minor(&bdx, &cdy, &cdx, &bdy));
+    let b_term = expansion_product(&lift(&bdx, &bdy), &minor(&cdx, &ady, &adx, &cdy));
+    let c_term = expansion_product(&lift(&cdx, &cdy), &minor(&adx, &bdy, &bdx, &ady));
+    most_significant(&expansion_sum(&expansion_sum(&a_term, &b_term), &c_term))
+}
@@ -0,0 +1,84 @@
+use crate::XY;
+use crate::predicates;
+
+use std::cmp::Ordering;
+
+// A coordinate type the triangulator can work in. Only the sign of orient2d is ever looked at, and that has to be
+// exact: floats go through the adaptive predicates, integers are worked out in wider integers.
+pub trait Scalar: Copy + PartialOrd + std::fmt::Debug
+{
+    fn orient2d(a: XY<Self>, b: XY<Self>, c: XY<Self>) -> f64;
+    fn is_finite(self) -> bool;
+    fn to_f64(self) -> f64;
+}
+
+impl Scalar for f32
+{
+    fn orient2d(a: XY<f32>, b: XY<f32>, c: XY<f32>) -> f64 { predicates::orient2d(a, b, c) }
+    fn is_finite(self) -> bool { f32::is_finite(self) }
+    fn to_f64(self) -> f64 { self as f64 }
+}
+
+impl Scalar for f64
+{
+    fn orient2d(a: XY<f64>, b: XY<f64>, c: XY<f64>) -> f64 { predicates::orient2d_f64([a.x, a.y], [b.x, b.y], [c.x, c.y]) }
+    fn is_finite(self) -> bool { f64::is_finite(self) }
+    fn to_f64(self) -> f64 { self }
+}
+
+impl Scalar for i32
+{
+    fn orient2d(a: XY<i32>, b: XY<i32>, c: XY<i32>) -> f64
+    {
+        let (a, b, c) = ((a.x as i128, a.y as i128), (b.x as i128, b.y as i128), (c.x as i128, c.y as i128));
+        ((a.0 - c.0) * (b.1 - c.1) - (a.1 - c.1) * (b.0 - c.0)) as f64
+    }
+    fn is_finite(self) -> bool { true }
+    fn to_f64(self) -> f64 { self as f64 }
+}
+
+impl Scalar for i64
+{
+    fn orient2d(a: XY<i64>, b: XY<i64>, c: XY<i64>) -> f64
+    {
+        let (a, b, c) = ((a.x as i128, a.y as i128), (b.x as i128, b.y as i128), (c.x as i128, c.y as i128));
+        let (left, right) = ((a.0 - c.0, b.1 - c.1), (a.1 - c.1, b.0 - c.0));
+        match (left.0.checked_mul(left.1), right.0.checked_mul(right.1))
+        {
+            (Some(left), Some(right)) => left.checked_sub(right).map_or_else(|| if left > right { 1.0 } else { -1.0 }, |det| det as f64),
+            _ => match compare_products(left, right)
+            {
+                Ordering::Greater => 1.0,
+                Ordering::Less => -1.0,
+                Ordering::Equal => 0.0,
+            },
+        }
+    }
+    fn is_finite(self) -> bool { true }
+    fn to_f64(self) -> f64 { self as f64 }
+}
+
+// Differences of i64s need 65 bits, so their products can need up to 130, more than an i128 holds
+fn compare_products(left: (i128, i128), right: (i128, i128)) -> Ordering
+{
+    let sign = |(a, b): (i128, i128)| a.signum() * b.signum();
+    let (left_sign, right_sign) = (sign(left), sign(right));
+    if left_sign != right_sign || left_sign == 0
+        { return left_sign.cmp(&right_sign); }
+
+    let magnitude = |(a, b): (i128, i128)| wide_product(a.unsigned_abs(), b.unsigned_abs());
+    let ordering = magnitude(left).cmp(&magnitude(right));
+    if left_sign > 0 { ordering } else { ordering.reverse() }
+}
+
+// (high, low) halves of the 256 bit product, the inputs are under 2^66
+fn wide_product(a: u128, b: u128) -> (u128, u128)
+{
+    let (a_high, a_low) = (a >> 64, a & u64::MAX as u128);
+    let (b_high, b_low) = (b >> 64, b & u64::MAX as u128);
+
+    let middle = a_low * b_high + a_high * b_low;
+    let (low, carry) = (a_low * b_low).overflowing_add(middle << 64);
+    let high = a_high * b_high + (middle >> 64) + carry as u128;
+    (high, low)
+}
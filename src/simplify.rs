@@ -0,0 +1,222 @@
+use crate::XY;
+use crate::validation::{self, Issue};
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn to_f64(xy: XY) -> (f64, f64) { (xy.x as f64, xy.y as f64) }
+
+// How far p is from the line through a and b, or from a if they're the same point
+fn distance_from_line(p: XY, a: XY, b: XY) -> f64
+{
+    let (p, a, b) = (to_f64(p), to_f64(a), to_f64(b));
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx.hypot(dy);
+    if length == 0.0
+        { return (p.0 - a.0).hypot(p.1 - a.1); }
+    (dx * (p.1 - a.1) - dy * (p.0 - a.0)).abs() / length
+}
+
+fn triangle_area(a: XY, b: XY, c: XY) -> f64
+{
+    let (a, b, c) = (to_f64(a), to_f64(b), to_f64(c));
+    ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)).abs() / 2.0
+}
+
+// Douglas-Peucker, every point left out is within tolerance of the edge that replaces it
+pub fn douglas_peucker(ring: &[XY], tolerance: f32) -> Vec<XY>
+{
+    let count = ring.len();
+    if count < 4
+        { return ring.to_vec(); }
+
+    // A ring has no ends to start from, so it's split in two at the first point and the one furthest from it
+    let far = (1..count).max_by(|&a, &b| distance_from_line(ring[a], ring[0], ring[0]).total_cmp(&distance_from_line(ring[b], ring[0], ring[0]))).unwrap();
+    let mut kept = vec!(false; count);
+    (kept[0], kept[far]) = (true, true);
+
+    let mut spans = vec!((0, far), (far, count));
+    while let Some((from, to)) = spans.pop()
+    {
+        let Some(i) = furthest_between(ring, from, to)
+            else { continue; };
+        if distance_from_line(ring[i % count], ring[from % count], ring[to % count]) > tolerance as f64
+        {
+            kept[i % count] = true;
+            spans.push((from, i));
+            spans.push((i, to));
+        }
+    }
+
+    keep_simple(ring, kept)
+}
+
+// Visvalingam-Whyatt, points go one at a time starting with whichever makes the smallest triangle with its neighbours,
+// until they all make one at least as big as tolerance. The tolerance is an area rather than a distance.
+pub fn visvalingam_whyatt(ring: &[XY], tolerance: f32) -> Vec<XY>
+{
+    let count = ring.len();
+    if count < 4
+        { return ring.to_vec(); }
+
+    let mut previous: Vec<usize> = (0..count).map(|i| (i + count - 1) % count).collect();
+    let mut next: Vec<usize> = (0..count).map(|i| (i + 1) % count).collect();
+    let area = |i: usize, previous: &[usize], next: &[usize]| triangle_area(ring[previous[i]], ring[i], ring[next[i]]);
+
+    // Areas are never negative, so their bits sort the same way they do. Entries go stale when a neighbour goes and
+    // are skipped if they don't match the latest area.
+    let mut areas: Vec<f64> = (0..count).map(|i| area(i, &previous, &next)).collect();
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = (0..count).map(|i| Reverse((areas[i].to_bits(), i))).collect();
+    let mut kept = vec!(true; count);
+    let mut left = count;
+
+    while let Some(Reverse((bits, i))) = heap.pop()
+    {
+        if !kept[i] || bits != areas[i].to_bits()
+            { continue; }
+        if left <= 3 || areas[i] >= tolerance as f64
+            { break; }
+
+        kept[i] = false;
+        left -= 1;
+        let (before, after) = (previous[i], next[i]);
+        next[before] = after;
+        previous[after] = before;
+        for neighbour in [before, after]
+        {
+            areas[neighbour] = area(neighbour, &previous, &next);
+            heap.push(Reverse((areas[neighbour].to_bits(), neighbour)));
+        }
+    }
+
+    keep_simple(ring, kept)
+}
+
+// The point strictly between from and to furthest from the line between them, to can go past the end of the ring
+fn furthest_between(ring: &[XY], from: usize, to: usize) -> Option<usize>
+{
+    let count = ring.len();
+    (from + 1..to).max_by(|&a, &b| distance_from_line(ring[a % count], ring[from % count], ring[to % count])
+        .total_cmp(&distance_from_line(ring[b % count], ring[from % count], ring[to % count])))
+}
+
+// Leaving points out can make edges cross that didn't before. Each edge caught up in a crossing, or anything else
+// that stops the ring being simple, gets back the point it left out that was furthest from it, until there's nothing
+// wrong or nothing left to put back. A ring that was simple to start with always comes out simple.
+fn keep_simple(ring: &[XY], mut kept: Vec<bool>) -> Vec<XY>
+{
+    let count = ring.len();
+    loop
+    {
+        let indices: Vec<usize> = (0..count).filter(|&i| kept[i]).collect();
+        let simplified: Vec<XY> = indices.iter().map(|&i| ring[i]).collect();
+        let issues = validation::validate(&simplified).issues;
+        if issues.is_empty()
+            { return simplified; }
+
+        let n = indices.len();
+        let mut edges = Vec::<usize /*Simplified Edge Index*/>::new();
+        for issue in issues
+        {
+            match issue
+            {
+                Issue::TooFewPoints { .. } => edges.extend(0..n),
+                Issue::NonFiniteCoordinate { .. } => return ring.to_vec(),
+                Issue::ZeroLengthEdge { index, .. } => edges.push(index),
+                Issue::DuplicateVertex { index, first_index, .. } => edges.extend([index, (index + n - 1) % n, first_index, (first_index + n - 1) % n]),
+                Issue::Spike { index, .. } => edges.extend([index, (index + n - 1) % n]),
+                Issue::SelfIntersection { edge_a, edge_b, .. } => edges.extend([edge_a, edge_b]),
+            }
+        }
+
+        let mut put_back = false;
+        for edge in edges
+        {
+            let (from, to) = (indices[edge], indices[(edge + 1) % n]);
+            let to = if to <= from { to + count } else { to };
+            if let Some(i) = furthest_between(ring, from, to)
+            {
+                put_back |= !kept[i % count];
+                kept[i % count] = true;
+            }
+        }
+
+        // Whatever's still wrong was already wrong with the ring that came in
+        if !put_back
+            { return simplified; }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator;
+
+    fn distance_to_ring(ring: &[XY], p: XY) -> f64
+    {
+        (0..ring.len()).map(|i|
+        {
+            let (a, b) = (to_f64(ring[i]), to_f64(ring[(i + 1) % ring.len()]));
+            let (d, w) = ((b.0 - a.0, b.1 - a.1), (p.x as f64 - a.0, p.y as f64 - a.1));
+            let t = ((w.0 * d.0 + w.1 * d.1) / (d.0 * d.0 + d.1 * d.1)).clamp(0.0, 1.0);
+            (w.0 - t * d.0).hypot(w.1 - t * d.1)
+        }).fold(f64::INFINITY, f64::min)
+    }
+
+    // A long thin strip with bumpy sides closer together than the tolerance, leaving points out of one side can
+    // easily cut across the other
+    fn thin_strip() -> Vec<XY>
+    {
+        let bump = |i: usize| 0.03 + 0.025 * ((i * 7) % 5) as f32 / 4.0;
+        let bottom = (0..100).map(|i| XY::new(i as f32 / 100.0, -bump(i)));
+        let top = (0..100).rev().map(|i| XY::new(i as f32 / 100.0, bump(i + 3)));
+        bottom.chain(top).collect()
+    }
+
+    #[test]
+    fn points_left_out_are_within_tolerance()
+    {
+        let wobbly: Vec<XY> = (0..200).map(|i|
+        {
+            let (angle, radius) = (i as f32 * std::f32::consts::TAU / 200.0, 1.0 + 0.005 * ((i * 13) % 7) as f32 / 6.0);
+            XY::new(radius * angle.cos(), radius * angle.sin())
+        }).collect();
+
+        let simplified = douglas_peucker(&wobbly, 0.02);
+        assert!(simplified.len() < 50, "{} points", simplified.len());
+        assert!(wobbly.iter().all(|&p| distance_to_ring(&simplified, p) <= 0.02 + 1e-6));
+        assert!(simplified.iter().all(|p| wobbly.iter().any(|q| p.x == q.x && p.y == q.y)));
+
+        let simplified = visvalingam_whyatt(&wobbly, 0.001);
+        assert!(simplified.len() < 100, "{} points", simplified.len());
+        assert!(validation::validate(&simplified).is_simple());
+
+        assert_eq!(douglas_peucker(&wobbly, 0.0).len(), wobbly.len());
+    }
+
+    #[test]
+    fn simplified_rings_stay_simple()
+    {
+        // Straightening out the dip in the bottom would cut across the tip of the spike coming down from the top
+        let spike = vec!(XY::new(0.0, 0.0), XY::new(0.5, -0.05), XY::new(1.0, 0.0), XY::new(1.0, 1.0), XY::new(0.55, 1.0), XY::new(0.5, -0.02),
+            XY::new(0.45, 1.0), XY::new(0.0, 1.0));
+        for simplified in [douglas_peucker(&spike, 0.06), visvalingam_whyatt(&spike, 0.03)]
+        {
+            assert_eq!(simplified.len(), spike.len());
+            assert!(validation::validate(&simplified).is_simple());
+        }
+
+        let strip = thin_strip();
+        assert!(validation::validate(&strip).is_simple());
+        for tolerance in [0.02, 0.05, 0.1, 0.5]
+        {
+            for simplified in [douglas_peucker(&strip, tolerance), visvalingam_whyatt(&strip, tolerance * tolerance)]
+            {
+                assert!(simplified.len() < strip.len());
+                assert!(validation::validate(&simplified).is_simple(), "{} {:?}", tolerance, validation::validate(&simplified).issues);
+                assert_eq!(triangulator::try_calc(&simplified).map(|triangles| triangles.len()), Ok(simplified.len() - 2));
+            }
+        }
+    }
+}
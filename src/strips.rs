@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+// Triangle strips through counter-clockwise triangles, laid out so the GPU's alternating winding gives every
+// triangle back the way round it came in. Strips are joined with restart_index between them if there is one, or by
+// repeating vertices to make triangles with no area, which the GPU skips, if not. There's no fan output to go with
+// it: wgpu has no triangle fan topology to draw one with, and a fan can still go in a strip by coming back to its
+// middle vertex after each vertex round its edge.
+pub fn from_triangles(triangles: &[[u32; 3]], restart_index: Option<u32>) -> Vec<u32>
+{
+    let mut owners = HashMap::<(u32, u32) /*Directed Edge*/, usize /*Triangle Index*/>::new();
+    for (i, triangle) in triangles.iter().enumerate()
+    {
+        for k in 0..3
+            { owners.insert((triangle[k], triangle[(k + 1) % 3]), i); }
+    }
+
+    let mut used = vec!(false; triangles.len());
+    let mut strips = Vec::<Vec<u32>>::new();
+    for start in 0..triangles.len()
+    {
+        if used[start]
+            { continue; }
+
+        // Whichever edge of the first triangle the strip leaves by makes a difference to how far it gets
+        used[start] = true;
+        let triangle = triangles[start];
+        let rotations = (0..3).map(|r| [triangle[r], triangle[(r + 1) % 3], triangle[(r + 2) % 3]]);
+        let best = rotations.max_by_key(|first|
+        {
+            let (_, taken) = extend(*first, triangles, &owners, &mut used);
+            for &i in &taken { used[i] = false; }
+            taken.len()
+        }).unwrap();
+
+        strips.push(extend(best, triangles, &owners, &mut used).0);
+    }
+
+    join(&strips, restart_index)
+}
+
+// Follows the strip on from its first triangle for as long as there's an unused triangle across one of the last
+// triangle's edges out of its last vertex, marking them used. Triangle k of a strip shares the edge from vertex k to
+// k+1 with the one before it, which runs forwards through it when k is even and backwards when k is odd.
+fn extend(first: [u32; 3], triangles: &[[u32; 3]], owners: &HashMap<(u32, u32), usize>, used: &mut [bool]) -> (Vec<u32>, Vec<usize>)
+{
+    let mut strip = first.to_vec();
+    let mut taken = vec!();
+    let across = |x: u32, y: u32, k: usize, used: &[bool]|
+    {
+        let edge = if k.is_multiple_of(2) { (x, y) } else { (y, x) };
+        owners.get(&edge).copied().filter(|&i| !used[i])
+    };
+
+    loop
+    {
+        let n = strip.len();
+        let (w, x, y) = (strip[n - 3], strip[n - 2], strip[n - 1]);
+
+        // Going on across w to y instead of x to y takes one more copy of w, the triangle it makes has no area
+        let next = match across(x, y, n - 2, used)
+        {
+            Some(i) => Some((i, x)),
+            None => across(w, y, n - 1, used).map(|i| { strip.insert(n - 1, w); (i, w) }),
+        };
+        let Some((i, x)) = next
+            else { break; };
+
+        used[i] = true;
+        taken.push(i);
+        strip.push(triangles[i].into_iter().find(|&v| v != x && v != y).unwrap());
+    }
+    (strip, taken)
+}
+
+pub fn join(strips: &[Vec<u32>], restart_index: Option<u32>) -> Vec<u32>
+{
+    let mut indices = Vec::<u32>::new();
+    for strip in strips.iter().filter(|strip| !strip.is_empty())
+    {
+        if let Some(&last) = indices.last()
+        {
+            match restart_index
+            {
+                Some(restart_index) => indices.push(restart_index),
+                None =>
+                {
+                    indices.push(last);
+                    indices.push(strip[0]);
+
+                    // Each strip has to start on an even triangle to keep its winding
+                    if indices.len() % 2 == 1
+                        { indices.push(strip[0]); }
+                },
+            }
+        }
+        indices.extend_from_slice(strip);
+    }
+    indices
+}
@@ -0,0 +1,300 @@
+use crate::XY;
+use crate::triangulator::Triangle;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum LineJoin { Miter, Round, Bevel }
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum LineCap { Butt, Square, Round }
+
+#[derive(Debug, Clone)]
+pub struct StrokeStyle
+{
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    pub miter_limit: f32, // How many half widths a miter can reach out before it's bevelled instead, as in SVG
+    pub dashes: Vec<f32>, // Lengths on and off in turn, an odd number of them goes round twice
+    pub dash_offset: f32,
+    pub tolerance: f32, // How far round joins and caps can stray from a true circle
+}
+
+impl StrokeStyle
+{
+    pub fn new(width: f32) -> StrokeStyle
+    {
+        StrokeStyle { width, join: LineJoin::Miter, cap: LineCap::Butt, miter_limit: 4.0, dashes: vec!(), dash_offset: 0.0, tolerance: width / 100.0 }
+    }
+}
+
+fn add(a: XY, b: XY) -> XY { XY::new(a.x + b.x, a.y + b.y) }
+fn sub(a: XY, b: XY) -> XY { XY::new(a.x - b.x, a.y - b.y) }
+fn scale(a: XY, s: f32) -> XY { XY::new(a.x * s, a.y * s) }
+fn cross(a: XY, b: XY) -> f32 { a.x * b.y - a.y * b.x }
+fn length(a: XY) -> f32 { a.x.hypot(a.y) }
+
+// More dashes than this along the path and it's drawn whole instead, they'd be too small to see and too many to draw
+const MOST_DASHES: f64 = (1 << 20) as f64;
+
+// The triangles go the same way round as those from calc, and a stroke is meant to be drawn over a fill of the same
+// path. Where a stroke overlaps itself, at joins and where a path crosses itself, the triangles overlap as well.
+pub fn stroke(points: &[XY], is_closed: bool, style: &StrokeStyle) -> Vec<Triangle>
+{
+    let mut path = without_repeats(points);
+    if is_closed && path.len() > 1 && path[0].x == path[path.len() - 1].x && path[0].y == path[path.len() - 1].y
+        { path.pop(); }
+
+    let mut stroker = Stroker { style, triangles: vec!() };
+    if path.len() < 2
+        { return stroker.triangles; }
+
+    let dashes: Vec<f64> = style.dashes.iter().map(|&dash| dash as f64).collect();
+    let dashes = if dashes.len() % 2 == 1 { dashes.repeat(2) } else { dashes };
+    let period: f64 = dashes.iter().sum();
+    let closing = if is_closed { length_f64(path[path.len() - 1], path[0]) } else { 0.0 };
+    let path_length: f64 = path.windows(2).map(|pair| length_f64(pair[0], pair[1])).sum::<f64>() + closing;
+    if dashes.is_empty() || dashes.iter().any(|dash| !dash.is_finite() || *dash < 0.0) || period <= 0.0
+        || path_length / period * dashes.len() as f64 > MOST_DASHES
+        { stroker.polyline(&path, is_closed); }
+    else
+    {
+        if is_closed
+            { path.push(path[0]); }
+        for dash in split_dashes(&path, &dashes, style.dash_offset)
+        {
+            let dash = without_repeats(&dash);
+            if dash.len() > 1
+                { stroker.polyline(&dash, false); }
+        }
+    }
+
+    stroker.triangles
+}
+
+// An edge with no length has no direction to put the width either side of
+fn without_repeats(points: &[XY]) -> Vec<XY>
+{
+    let mut path: Vec<XY> = Vec::with_capacity(points.len());
+    for &point in points
+    {
+        if path.last().is_none_or(|last| last.x != point.x || last.y != point.y)
+            { path.push(point); }
+    }
+    path
+}
+
+fn length_f64(a: XY, b: XY) -> f64 { (b.x as f64 - a.x as f64).hypot(b.y as f64 - a.y as f64) }
+
+// The pieces of the path that are on, each as its own open path. How far along each edge the dashes change is
+// measured from the start of the edge in f64, taking off one dash after another in f32 soon stops getting anywhere.
+fn split_dashes(path: &[XY], dashes: &[f64], offset: f32) -> Vec<Vec<XY>>
+{
+    let period: f64 = dashes.iter().sum();
+    let mut dash_index = 0;
+    let mut left_in_dash = dashes[0];
+    let mut skip = (offset as f64).rem_euclid(period);
+    while skip > 0.0
+    {
+        if skip < left_in_dash
+            { left_in_dash -= skip; skip = 0.0; }
+        else
+        {
+            skip -= left_in_dash;
+            dash_index = (dash_index + 1) % dashes.len();
+            left_in_dash = dashes[dash_index];
+        }
+    }
+
+    let mut pieces = vec!();
+    let mut piece = if dash_index % 2 == 0 { vec!(path[0]) } else { vec!() };
+    for pair in path.windows(2)
+    {
+        let (from, to) = (pair[0], pair[1]);
+        let edge_length = length_f64(from, to);
+        let mut along = 0.0;
+        while edge_length - along > left_in_dash
+        {
+            along += left_in_dash;
+            let t = along / edge_length;
+            let point = XY::new((from.x as f64 + (to.x as f64 - from.x as f64) * t) as f32, (from.y as f64 + (to.y as f64 - from.y as f64) * t) as f32);
+
+            if dash_index % 2 == 0
+            {
+                piece.push(point);
+                pieces.push(std::mem::take(&mut piece));
+            }
+            else
+                { piece = vec!(point); }
+
+            dash_index = (dash_index + 1) % dashes.len();
+            left_in_dash = dashes[dash_index];
+        }
+        left_in_dash -= edge_length - along;
+        if dash_index % 2 == 0
+            { piece.push(to); }
+    }
+    pieces.push(piece);
+
+    pieces
+}
+
+struct Stroker<'a>
+{
+    style: &'a StrokeStyle,
+    triangles: Vec<Triangle>,
+}
+
+impl<'a> Stroker<'a>
+{
+    fn push(&mut self, a: XY, b: XY, c: XY)
+    {
+        let side = cross(sub(b, a), sub(c, a));
+        if side > 0.0
+            { self.triangles.push((a, b, c)); }
+        else if side < 0.0
+            { self.triangles.push((a, c, b)); }
+    }
+
+    // Half the width to the left of the way the edge goes
+    fn normal(&self, from: XY, to: XY) -> XY
+    {
+        let direction = sub(to, from);
+        scale(XY::new(-direction.y, direction.x), self.style.width / 2.0 / length(direction))
+    }
+
+    fn polyline(&mut self, path: &[XY], is_closed: bool)
+    {
+        let count = path.len();
+        let edge_count = if is_closed { count } else { count - 1 };
+        for i in 0..edge_count
+        {
+            let (from, to) = (path[i], path[(i + 1) % count]);
+            let normal = self.normal(from, to);
+            self.push(add(from, normal), sub(from, normal), sub(to, normal));
+            self.push(add(from, normal), sub(to, normal), add(to, normal));
+        }
+
+        let joins = if is_closed { 0..count } else { 1..count - 1 };
+        for i in joins
+            { self.join(path[(i + count - 1) % count], path[i], path[(i + 1) % count]); }
+
+        if !is_closed
+        {
+            self.cap(path[1], path[0]);
+            self.cap(path[count - 2], path[count - 1]);
+        }
+    }
+
+    // Fills the gap on the outside of the turn at b, the inside is already covered by the two edges overlapping
+    fn join(&mut self, a: XY, b: XY, c: XY)
+    {
+        let (normal_in, normal_out) = (self.normal(a, b), self.normal(b, c));
+        let turn = cross(sub(b, a), sub(c, b));
+        let side = if turn > 0.0 { -1.0 } else { 1.0 };
+        let (outer_in, outer_out) = (scale(normal_in, side), scale(normal_out, side));
+
+        match self.style.join
+        {
+            LineJoin::Bevel => self.push(b, add(b, outer_in), add(b, outer_out)),
+            LineJoin::Round => self.fan(b, outer_in, outer_out),
+            LineJoin::Miter =>
+            {
+                // The miter reaches out 1 / cos(half the turn) half widths, along the line between the two normals
+                let half_width = self.style.width / 2.0;
+                let middle = add(outer_in, outer_out);
+                let cos_half = length(middle) / 2.0 / half_width;
+                if cos_half * self.style.miter_limit < 1.0 || length(middle) == 0.0
+                    { self.push(b, add(b, outer_in), add(b, outer_out)); }
+                else
+                {
+                    let tip = add(b, scale(middle, half_width / cos_half / length(middle)));
+                    self.push(b, add(b, outer_in), tip);
+                    self.push(b, tip, add(b, outer_out));
+                }
+            },
+        }
+    }
+
+    // The cap on the end at b of the edge from a
+    fn cap(&mut self, a: XY, b: XY)
+    {
+        let normal = self.normal(a, b);
+        let forward = XY::new(normal.y, -normal.x);
+        match self.style.cap
+        {
+            LineCap::Butt => (),
+            LineCap::Square =>
+            {
+                self.push(add(b, normal), sub(b, normal), add(sub(b, normal), forward));
+                self.push(add(b, normal), add(sub(b, normal), forward), add(add(b, normal), forward));
+            },
+            LineCap::Round =>
+            {
+                self.fan(b, normal, forward);
+                self.fan(b, forward, scale(normal, -1.0));
+            },
+        }
+    }
+
+    // Triangles round the centre from one spoke to the other the short way, each step close enough to the circle
+    fn fan(&mut self, centre: XY, from: XY, to: XY)
+    {
+        let radius = self.style.width / 2.0;
+        let angle = cross(from, to).atan2(from.x * to.x + from.y * to.y);
+        let step = 2.0 * (1.0 - (self.style.tolerance / radius).min(1.0)).acos();
+        let count = if step > 0.0 { ((angle.abs() / step).ceil() as usize).clamp(1, 1024) } else { 1024 };
+
+        let start = from.y.atan2(from.x);
+        let mut previous = from;
+        for i in 1..=count
+        {
+            let spoke = if i == count { to } else { scale(XY::new((start + angle * i as f32 / count as f32).cos(), (start + angle * i as f32 / count as f32).sin()), radius) };
+            self.push(centre, add(centre, previous), add(centre, spoke));
+            previous = spoke;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn area(triangles: &[Triangle]) -> f64
+    {
+        triangles.iter().map(|&(a, b, c)| cross(sub(b, a), sub(c, a)) as f64 / 2.0).sum()
+    }
+
+    #[test]
+    fn dashes_cover_their_share_of_the_line()
+    {
+        let line = vec!(XY::new(0.0, 0.0), XY::new(10.0, 0.0));
+        let style = StrokeStyle { dashes: vec!(1.0, 1.0), ..StrokeStyle::new(2.0) };
+        let triangles = stroke(&line, false, &style);
+        assert_eq!(triangles.len(), 10);
+        assert_eq!(area(&triangles), 10.0);
+
+        // A hundred thousand dashes along one edge, each a little off where f32 can put it but none lost or gained
+        let long = vec!(XY::new(0.0, 0.0), XY::new(100_000.0, 0.0));
+        let style = StrokeStyle { dashes: vec!(0.3, 0.7), ..StrokeStyle::new(2.0) };
+        let triangles = stroke(&long, false, &style);
+        assert_eq!(triangles.len(), 200_000);
+        assert!((area(&triangles) - 60_000.0).abs() < 600.0, "{}", area(&triangles));
+    }
+
+    #[test]
+    fn dashes_too_fine_to_see_draw_the_line_whole()
+    {
+        let line = vec!(XY::new(0.0, 0.0), XY::new(3000.0, 0.0));
+        let whole = stroke(&line, false, &StrokeStyle::new(2.0));
+
+        let start = Instant::now();
+        for dashes in [vec!(1e-4, 1e-4), vec!(1e-30), vec!(0.0, 0.0), vec!(1.0, -1.0), vec!(1.0, f32::NAN), vec!(f32::INFINITY)]
+        {
+            let style = StrokeStyle { dashes: dashes.clone(), ..StrokeStyle::new(2.0) };
+            assert_eq!(stroke(&line, false, &style).len(), whole.len(), "{:?}", dashes);
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -1,427 +1,1005 @@
-use crate::{XY};
-
-#[derive(Debug, Clone)]
-struct RankyPoint<'a>
-{
-    xy: XY,
-    path: &'a Rankygon,
-    index: usize,
-}
-
-impl<'a> RankyPoint<'a>
-{
-    fn before(&self) -> RankyPoint<'a>
-        { return self.path.get_adjacent(self.index, -1) }
-
-    fn after(&self) -> RankyPoint<'a>
-        { return self.path.get_adjacent(self.index, 1) }
-}
-
-#[derive(Debug, Clone)]
-struct Rankygon
-{
-    points: Vec<XY>,
-    rank_to_index: Vec<usize>,
-}
-
-impl Rankygon
-{
-    fn new(points: &[XY]) -> Rankygon
-    {
-        let path_length = points.len();
-
-        let mut ranky = Rankygon
-        {
-            points: points.to_vec(),
-            rank_to_index: Vec::with_capacity(path_length),
-        };
-
-        for i in 0..path_length
-            { ranky.rank_to_index.push(i) }; // [0, 1, 2, 3, ..., path_length - 1]
-            
-        ranky.rank_to_index.sort_by(|a, b|
-        { 
-            use std::cmp::Ordering;
-            let a = &points[*a]; let b = &points[*b];
-            if a.x < b.x { return Ordering::Less; }
-            else if a.x > b.x { return Ordering::Greater; }
-            else if a.y < b.y { return Ordering::Less; }
-            else if a.y > b.y { return Ordering::Greater; }
-            else { return Ordering::Equal; }
-        }); 
-
-        return ranky;
-    }
-
-    fn index(&self, index: usize) -> RankyPoint
-    {
-        RankyPoint
-        {
-            xy: self.points[index],
-            path: &self,
-            index: index,
-        }
-    }
-
-    fn rank(&self, rank: usize) -> RankyPoint
-    {
-        self.index(self.rank_to_index[rank])
-    }
-
-    fn get_adjacent(&self, index: usize, amount: i32) -> RankyPoint
-    {
-        self.index(((index as i32) + amount).rem_euclid(self.points.len() as i32) as usize)
-    }
-
-    fn len(&self) -> usize
-    {
-        self.points.len()
-    }
-}
-
-#[derive(Debug, PartialEq, Copy, Clone)]
-enum ChainOption { Top, Bottom, Both }
-
-#[derive(Debug, Clone)] 
-struct MonoPoint
-{
-    xy: XY,
-	chain: ChainOption, 
-}
-
-#[derive(Debug, Clone)] 
-struct Monotone
-{
-    points: Vec<MonoPoint>
-}
-
-impl Monotone
-{
-    fn new() -> Monotone
-    {
-        Monotone { points: Vec::new() }
-    }
-
-    fn push(&mut self, xy: XY, chain: ChainOption)
-    {
-        self.points.push(MonoPoint{ xy, chain });
-    }
-}
-
-use std::collections::HashMap;
-
-#[derive(Debug, Clone)] 
-struct RankygonToMonos
-{
-    rankygon: Rankygon,
-    monos: Vec<Monotone>,
-    chain_followers: HashMap::<usize /*Follower Index*/, (usize /*Mono Index*/, ChainOption)>,
-    chain_mergers: HashMap::<usize /*Follower Index*/, (usize /*Top Mono Index*/, usize /*Bottom Mono Index*/)>,
-    merged_monos: HashMap::<usize /*Mono Index*/, (usize /* Other Mono Index*/, bool /* is lookup mono the top mono */)>,
-    mono_quads: HashMap::<usize /*Mono Index*/, (usize /*TopFront*/, usize /*TopBack*/, usize /*BottomFront*/, usize /*BottomBack*/)>
-}
-
-impl RankygonToMonos
-{
-    fn convert(rankygon: Rankygon) -> Vec<Monotone>
-    {
-        let mut container = RankygonToMonos
-        {
-            rankygon: rankygon,
-            monos: Vec::<Monotone>::new(),
-            chain_followers: HashMap::<usize, (usize, ChainOption)>::new(),
-            chain_mergers: HashMap::<usize, (usize, usize)>::new(),
-            merged_monos: HashMap::<usize, (usize, bool)>::new(),
-            mono_quads: HashMap::<usize, (usize, usize, usize, usize)>::new()
-        };
-
-        for r in 0..container.rankygon.len()
-		{
-            let current_index = container.rankygon.rank(r).index;
-
-            if container.chain_followers.contains_key(&current_index)
-                { container.edges_across(r); }
-            else if container.chain_mergers.contains_key(&current_index)
-                { container.edges_backward(r);}
-            else 
-                { container.edges_forward(r); }
-		}
-
-        return container.monos;
-    }
-
-    fn edges_forward(&mut self, current_rank: usize)
-    {
-        let (current_index, current_xy, top_follower_index, bottom_follower_index) =
-        {
-            let current = self.rankygon.rank(current_rank);
-            let a = current.before(); let b = current.after();
-            if a.xy.y < b.xy.y 
-                { (current.index, current.xy, a.index, b.index) } 
-            else 
-                { (current.index, current.xy, b.index, a.index) }
-        };
-
-        let point_within_triangle = |p: &XY, a: &XY, b: &XY, c: &XY| -> bool
-        { 
-            let w1 = ( a.x*(c.y-a.y) + (p.y-a.y)*(c.x-a.x) - p.x*(c.y-a.y) ) / ( (b.y-a.y)*(c.x-a.x) - (b.x-a.x)*(c.y-a.y) );
-            let w2 = ( p.y - a.y - w1*(b.y-a.y) ) / (c.y-a.y);
-            return (w1 >= 0.0) && (w2 >= 0.0) && (w1 + w2 <= 1.0);
-        };
-        		
-		let mut split_mono_index = None;
-		for (m, q) in self.mono_quads.iter()
-		{
-            let tf = &self.rankygon.index(q.0).xy;
-            let tb = &self.rankygon.index(q.1).xy;
-            let bf = &self.rankygon.index(q.2).xy;
-            let bb = &self.rankygon.index(q.3).xy;
-
-			if point_within_triangle(&current_xy, tf, tb, bb) || point_within_triangle(&current_xy, tf, bb, bf)
-			{ 
-                split_mono_index = Some(*m);
-                break;
-            }
-		}
-
-        if split_mono_index == None
-        {
-            let mut mono = Monotone::new();
-            mono.push(current_xy, ChainOption::Both);
-            self.monos.push(mono);
-            let mono_index = self.monos.len() - 1;
-
-            self.mono_quads.insert(mono_index, (0, 0, 0, 0));
-            let current_index = current_index;
-            self.setup_followers(current_index, top_follower_index, mono_index, ChainOption::Top);
-            self.setup_followers(current_index, bottom_follower_index, mono_index, ChainOption::Bottom);
-        }
-        else
-        {
-            let split_mono_index = split_mono_index.unwrap();
-
-            if !self.merged_monos.contains_key(&split_mono_index)
-            {
-                let (split_mono_bottom_prev_added, split_mono_bottom_follower) = 
-				{
-					let q = self.mono_quads.get(&split_mono_index).unwrap();
-					(q.2, q.3)
-				};
-                
-                self.chain_followers.remove(&split_mono_bottom_follower);
-                self.monos[split_mono_index].push(current_xy, ChainOption::Bottom);
-                self.setup_followers(current_index, top_follower_index, split_mono_index, ChainOption::Bottom);
-
-                let mut mono = Monotone::new();
-                mono.push(self.rankygon.index(split_mono_bottom_prev_added).xy, ChainOption::Both);
-                mono.push(current_xy, ChainOption::Top);
-                self.monos.push(mono);
-                let mono_index = self.monos.len() - 1;
-
-                self.mono_quads.insert(mono_index, (0, 0, 0, 0));
-                self.setup_followers(split_mono_bottom_prev_added, bottom_follower_index, mono_index, ChainOption::Top);
-                self.setup_followers(split_mono_bottom_prev_added, split_mono_bottom_follower, mono_index, ChainOption::Bottom);
-            }
-            else
-            {
-                let (other_mono_index, split_mono_top_or_bottom) = self.merged_monos.remove(&split_mono_index).unwrap();
-                self.merged_monos.remove(&other_mono_index);
-
-                let (top_mono_index, bottom_mono_index) = 
-					if split_mono_top_or_bottom
-						{(split_mono_index, other_mono_index)}
-					else 
-						{(other_mono_index, split_mono_index)};
-
-                self.monos[top_mono_index].push(current_xy, ChainOption::Bottom);
-                self.monos[bottom_mono_index].push(current_xy, ChainOption::Top);
-                
-                self.setup_followers(current_index, top_follower_index, top_mono_index, ChainOption::Bottom);
-                self.setup_followers(current_index, bottom_follower_index, bottom_mono_index, ChainOption::Top);
-            }
-        }
-    }
-
-    fn edges_across(&mut self, current_rank: usize)
-    {
-        let current = self.rankygon.rank(current_rank);
-        let (mut mono_index, chain_type) = self.chain_followers.remove(&current.index).unwrap();
-
-        if self.merged_monos.contains_key(&mono_index)
-        {
-            let (other_mono_index, _) = self.merged_monos.remove(&mono_index).unwrap();
-            self.merged_monos.remove(&other_mono_index);
-            self.monos[mono_index].push(current.xy, ChainOption::Both);
-            mono_index = other_mono_index
-        };
-
-        let mono = &mut self.monos[mono_index];
-        mono.push(current.xy, chain_type);
-
-        let follower_index = 
-        {
-            let a = current.before(); let b = current.after();
-            if a.xy.x < b.xy.x { b.index }
-            else if a.xy.x > b.xy.x { a.index }
-            else if a.xy.y < b.xy.y { b.index }
-            else { a.index  }
-        };
-
-        let current_index = current.index;
-        self.setup_followers(current_index, follower_index, mono_index, chain_type);
-    }
-
-    fn edges_backward(&mut self, current_rank: usize)
-    {
-        let current = self.rankygon.rank(current_rank);
-        let (top_mono_index, bottom_mono_index) = self.chain_mergers.remove(&current.index).unwrap();
-
-        if top_mono_index == bottom_mono_index
-		{ 
-            self.monos[top_mono_index].push(current.xy, ChainOption::Both);
-        }
-        else if !self.merged_monos.contains_key(&top_mono_index)
-        {
-            self.merged_monos.insert(top_mono_index, (bottom_mono_index, true));
-            self.merged_monos.insert(bottom_mono_index, (top_mono_index, false));
-
-            {
-                let top_quad_clone = self.mono_quads.get(&top_mono_index).unwrap().clone();
-                let bottom_quad = self.mono_quads.get_mut(&bottom_mono_index).unwrap();
-                bottom_quad.0 = top_quad_clone.0;
-                bottom_quad.1 = top_quad_clone.1;
-            }
-
-            {
-                let bottom_quad_clone = self.mono_quads.get(&bottom_mono_index).unwrap().clone();
-                let top_quad = self.mono_quads.get_mut(&top_mono_index).unwrap();
-                top_quad.2 = bottom_quad_clone.2;
-                top_quad.3 = bottom_quad_clone.3;
-            }
-
-            self.monos[top_mono_index].push(current.xy, ChainOption::Bottom);
-			self.monos[bottom_mono_index].push(current.xy, ChainOption::Top);
-        }
-        else
-        {
-            self.merged_monos.remove(&top_mono_index);
-            self.merged_monos.remove(&bottom_mono_index);
-            
-            self.monos[top_mono_index].push(current.xy, ChainOption::Both);
-            self.monos[bottom_mono_index].push(current.xy, ChainOption::Both);
-        }
-    }
-
-    fn setup_followers(&mut self, current_index: usize, follower_index: usize, mono_index: usize, chain_type: ChainOption)
-    {
-        let quad = self.mono_quads.get_mut(&mono_index).unwrap();
-		if chain_type == ChainOption::Top
-			{ quad.0 = current_index; quad.1 = follower_index; }
-		else 
-			{ quad.2 = current_index; quad.3 = follower_index; }
-
-        if !self.chain_followers.contains_key(&follower_index)
-		{ 
-            self.chain_followers.insert(follower_index, (mono_index, chain_type));
-        }
-        else
-        { 
-            let (already_mono_index, _) = self.chain_followers.remove(&follower_index).unwrap();
-            if chain_type == ChainOption::Top
-                { self.chain_mergers.insert(follower_index, (already_mono_index, mono_index)); }
-            else
-                { self.chain_mergers.insert(follower_index, (mono_index, already_mono_index)); }
-        }
-    }
-}
-
-struct MonotoneToTriangles;
-
-impl MonotoneToTriangles
-{
-    fn convert(mono: Monotone) -> Vec<(XY, XY, XY)> 
-    {
-        let points = &mono.points;
-        let mut triangles = Vec::<(XY, XY, XY)>::new();
-        let mut a = &points[0];
-        let mut b = &points[1];
-
-        let mut skipped_num = 0;
-        for i in 2..points.len() 
-        {
-            let c = &points[i];
-
-            let side_of_line = (c.xy.x - a.xy.x) * (b.xy.y - a.xy.y) - (c.xy.y - a.xy.y) * (b.xy.x - a.xy.x);
-            if (b.chain == ChainOption::Top) && (side_of_line <= 0.0) || (b.chain == ChainOption::Bottom) && (side_of_line >= 0.0)
-            {
-                if b.chain == ChainOption::Top 
-                    { triangles.push((a.xy, b.xy, c.xy)); }
-                else 
-                    { triangles.push((a.xy, c.xy, b.xy)); };
-
-                if skipped_num == 0
-                {
-                    if b.chain == c.chain 
-                        { b = c; }
-                    else    
-                        { a = b; b = c; };
-                }
-                else 
-                {
-                    MonotoneToTriangles::skipped_section(points, &mut triangles, i-skipped_num-1, i);
-                    skipped_num = 0;
-
-                    if b.chain == c.chain 
-                        { b = c; }
-                    else
-                        { a = &mono.points[i-1]; b = c; }
-                }
-            } 
-            else 
-                { skipped_num += 1; }
-        }
-
-        triangles
-    }
-
-    fn skipped_section(points: &Vec<MonoPoint>, triangles: &mut Vec<(XY, XY, XY)>, front_index: usize, back_index: usize)
-    { 
-        if front_index + 1 == back_index
-            { return; }
-
-        let chain = points[front_index].chain;
-        let front_point = points[front_index].xy;
-        let back_point = points[back_index].xy;
-
-        let mut least_ear_index = front_index + 1;
-        let mut least_ear_point = points[least_ear_index].xy;
-        for i in front_index+2..back_index
-        {
-            let check_ear_point = points[i].xy;
-            if (chain == ChainOption::Top) && (check_ear_point.y > least_ear_point.y) || 
-               (chain == ChainOption::Bottom) && (check_ear_point.y < least_ear_point.y)
-            {
-                least_ear_index = i;
-                least_ear_point = check_ear_point;
-            };
-        };
-
-        if chain == ChainOption::Top
-            { triangles.push((front_point, least_ear_point, back_point)) }
-        else
-            { triangles.push((front_point, back_point, least_ear_point)) }
-        
-        MonotoneToTriangles::skipped_section(points, triangles, front_index, least_ear_index);
-        MonotoneToTriangles::skipped_section(points, triangles, least_ear_index, back_index);
-    }
-}
-
-pub fn calc(points: &[XY]) -> Vec<(XY, XY, XY)>
-{
-    let rankygon = Rankygon::new(points);
-    let monos = RankygonToMonos::convert(rankygon);
-    let mut triangles = Vec::<(XY, XY, XY)>::new();
-    for mono in monos
-        { triangles.append(&mut MonotoneToTriangles::convert(mono)); }
-    return triangles;
-}
\ No newline at end of file
+use crate::{XY};
+use crate::scalar::Scalar;
+use crate::intersections;
+use crate::strips;
+
+#[derive(Debug, Clone)]
+struct RankyPoint<'a, T: Scalar>
+{
+    xy: XY<T>,
+    path: &'a Rankygon<T>,
+    index: usize,
+}
+
+impl<'a, T: Scalar> RankyPoint<'a, T>
+{
+    fn before(&self) -> RankyPoint<'a, T>
+        { self.path.before(self.index) }
+
+    fn after(&self) -> RankyPoint<'a, T>
+        { self.path.after(self.index) }
+}
+
+#[derive(Debug, Clone)]
+struct Rankygon<T: Scalar>
+{
+    points: Vec<XY<T>>,
+    neighbours: Vec<(usize /*Before Index*/, usize /*After Index*/)>,
+    rank_to_index: Vec<usize>,
+}
+
+impl<T: Scalar> Rankygon<T>
+{
+    fn new(rings: &[&[XY<T>]]) -> Rankygon<T>
+    {
+        let path_length = rings.iter().map(|ring| ring.len()).sum();
+
+        let mut ranky = Rankygon
+        {
+            points: Vec::with_capacity(path_length),
+            neighbours: Vec::with_capacity(path_length),
+            rank_to_index: Vec::with_capacity(path_length),
+        };
+
+        for ring in rings
+        {
+            let start = ranky.points.len();
+            ranky.points.extend_from_slice(ring);
+            ranky.neighbours.extend((start..start + ring.len()).map(|index| (index, index)));
+
+            // Where the path stays put or turns straight back on itself it encloses nothing, the points there keep
+            // their indices but are never swept
+            let points = &ranky.points;
+            let mut kept = Vec::<usize>::with_capacity(ring.len());
+            for index in start..start + ring.len()
+            {
+                kept.push(index);
+                while kept.len() >= 2
+                {
+                    let n = kept.len();
+                    if same_place(points[kept[n - 2]], points[kept[n - 1]])
+                        { kept.pop(); }
+                    else if n >= 3 && folds_back(points[kept[n - 3]], points[kept[n - 2]], points[kept[n - 1]])
+                        { kept.remove(n - 2); }
+                    else 
+                        { break; }
+                }
+            }
+
+            // The end of the ring can double back onto its start too
+            loop
+            {
+                let n = kept.len();
+                let seam = (0..n).find(|&i| n >= 3 && folds_back(points[kept[(i + n - 1) % n]], points[kept[i]], points[kept[(i + 1) % n]]));
+                match seam
+                {
+                    Some(i) => { kept.remove(i); },
+                    None => break,
+                }
+            }
+
+            // Rings too short to enclose anything keep their place in the indices too
+            if kept.len() < 3
+                { continue; }
+
+            for (i, &index) in kept.iter().enumerate()
+                { ranky.neighbours[index] = (kept[(i + kept.len() - 1) % kept.len()], kept[(i + 1) % kept.len()]); }
+            ranky.rank_to_index.extend(kept);
+        }
+
+        // Where points share a place, the ones edges arrive at go first so that every edge ending there is gone
+        // from the sweep before any edge starting there goes in. The ones that only start edges go from the top
+        // down, so one tucked between another's two edges comes after it.
+        let sweep_keys: Vec<(usize /*Arrivals*/, StatusEdge<T> /*Top Edge*/)> = (0..ranky.points.len()).map(|index|
+        {
+            let (before, after) = ranky.neighbours[index];
+            let arrivals = [before, after].iter().filter(|&&neighbour| precedes(ranky.points[neighbour], ranky.points[index])).count();
+            let (top_follower, _) = ranky.forward_followers(index);
+            (arrivals, StatusEdge::new(ranky.points[index], ranky.points[top_follower]))
+        }).collect();
+
+        let points = &ranky.points;
+        ranky.rank_to_index.sort_by(|a, b|
+        { 
+            let (a_xy, b_xy) = (&points[*a], &points[*b]);
+            if a_xy.x < b_xy.x { Ordering::Less}
+            else if a_xy.x > b_xy.x { Ordering::Greater}
+            else if a_xy.y < b_xy.y { Ordering::Less}
+            else if a_xy.y > b_xy.y { Ordering::Greater}
+            else { sweep_keys[*b].0.cmp(&sweep_keys[*a].0).then_with(|| sweep_keys[*a].1.cmp(&sweep_keys[*b].1))}
+        }); 
+
+        ranky
+    }
+
+    fn index(&self, index: usize) -> RankyPoint<'_, T>
+    {
+        RankyPoint
+        {
+            xy: self.points[index],
+            path: self,
+            index,
+        }
+    }
+
+    fn rank(&self, rank: usize) -> RankyPoint<'_, T>
+    {
+        self.index(self.rank_to_index[rank])
+    }
+
+    fn before(&self, index: usize) -> RankyPoint<'_, T>
+    {
+        self.index(self.neighbours[index].0)
+    }
+
+    fn after(&self, index: usize) -> RankyPoint<'_, T>
+    {
+        self.index(self.neighbours[index].1)
+    }
+
+    fn len(&self) -> usize
+    {
+        self.rank_to_index.len()
+    }
+
+    // For a vertex with both its edges going forwards, which edge is on top
+    fn forward_followers(&self, index: usize) -> (usize /*Top Follower Index*/, usize /*Bottom Follower Index*/)
+    {
+        let (a, b) = self.neighbours[index];
+        let side_of_line = T::orient2d(self.points[a], self.points[b], self.points[index]);
+        if side_of_line > 0.0 || (side_of_line == 0.0 && self.points[a].y < self.points[b].y)
+            { (a, b) }
+        else
+            { (b, a) }
+    }
+}
+
+pub(crate) fn same_place<T: Scalar>(a: XY<T>, b: XY<T>) -> bool
+{
+    a.x == b.x && a.y == b.y
+}
+
+// Whether the path doubles back at b, either staying put or turning straight back the way it came
+pub(crate) fn folds_back<T: Scalar>(a: XY<T>, b: XY<T>, c: XY<T>) -> bool
+{
+    same_place(a, b) || (T::orient2d(a, b, c) == 0.0 && !same_place(b, c) && precedes(a, b) != precedes(b, c))
+}
+
+// The order of the sweep, left to right and then by y
+fn precedes<T: Scalar>(a: XY<T>, b: XY<T>) -> bool
+{
+    a.x < b.x || (a.x == b.x && a.y < b.y)
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ChainOption { Top, Bottom, Both }
+
+#[derive(Debug, Clone)] 
+struct MonoPoint<T: Scalar>
+{
+    index: usize,
+    xy: XY<T>,
+	chain: ChainOption, 
+}
+
+#[derive(Debug, Clone)] 
+struct Monotone<T: Scalar>
+{
+    points: Vec<MonoPoint<T>>
+}
+
+impl<T: Scalar> Monotone<T>
+{
+    fn new() -> Monotone<T>
+    {
+        Monotone { points: Vec::new() }
+    }
+
+    fn push(&mut self, index: usize, xy: XY<T>, chain: ChainOption)
+    {
+        self.points.push(MonoPoint{ index, xy, chain });
+    }
+}
+
+// An active edge, ordered along the sweep line. Active edges never cross, so two of them can be put in order by
+// where the later starting one starts, without knowing where the sweep line is
+#[derive(Debug, Clone, Copy)]
+struct StatusEdge<T: Scalar>
+{
+    left: XY<T>,
+    right: XY<T>,
+}
+
+impl<T: Scalar> StatusEdge<T>
+{
+    fn new(a: XY<T>, b: XY<T>) -> StatusEdge<T>
+    {
+        if precedes(a, b)
+            { StatusEdge { left: a, right: b } }
+        else
+            { StatusEdge { left: b, right: a } }
+    }
+
+    // Only meaningful when other started no later than self
+    fn cmp_to_earlier(&self, other: &StatusEdge<T>) -> Ordering
+    {
+        let mut side = T::orient2d(other.left, other.right, self.left);
+        if side == 0.0
+            { side = T::orient2d(other.left, other.right, self.right); }
+
+        if side > 0.0 { Ordering::Greater }
+        else if side < 0.0 { Ordering::Less }
+        else { Ordering::Equal }
+    }
+}
+
+impl<T: Scalar> Ord for StatusEdge<T>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        let ordering = 
+            if precedes(self.left, other.left)
+                { other.cmp_to_earlier(self).reverse() }
+            else
+                { self.cmp_to_earlier(other) };
+
+        // Edges in a line with each other only meet end to end, they still need telling apart to both be in the map
+        let coordinates = |edge: &StatusEdge<T>| [edge.left.x, edge.left.y, edge.right.x, edge.right.y];
+        ordering.then_with(|| coordinates(self).partial_cmp(&coordinates(other)).unwrap_or(Ordering::Equal))
+    }
+}
+
+impl<T: Scalar> PartialOrd for StatusEdge<T>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Scalar> PartialEq for StatusEdge<T>
+{
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl<T: Scalar> Eq for StatusEdge<T> {}
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone)] 
+struct RankygonToMonos<T: Scalar>
+{
+    rankygon: Rankygon<T>,
+    monos: Vec<Monotone<T>>,
+    chain_followers: HashMap::<usize /*Follower Index*/, (usize /*Mono Index*/, ChainOption)>,
+    chain_mergers: HashMap::<usize /*Follower Index*/, (usize /*Top Mono Index*/, usize /*Bottom Mono Index*/)>,
+    merged_monos: HashMap::<usize /*Mono Index*/, (usize /* Other Mono Index*/, bool /* is lookup mono the top mono */)>,
+    mono_quads: HashMap::<usize /*Mono Index*/, (usize /*TopFront*/, usize /*TopBack*/, usize /*BottomFront*/, usize /*BottomBack*/)>,
+    status: BTreeMap::<StatusEdge<T>, (usize /*Mono Index*/, ChainOption)>
+}
+
+impl<T: Scalar> RankygonToMonos<T>
+{
+    fn convert(rankygon: Rankygon<T>) -> Result<Vec<Monotone<T>>, TriangulationError>
+    {
+        let mut container = RankygonToMonos
+        {
+            rankygon,
+            monos: Vec::<Monotone<T>>::new(),
+            chain_followers: HashMap::<usize, (usize, ChainOption)>::new(),
+            chain_mergers: HashMap::<usize, (usize, usize)>::new(),
+            merged_monos: HashMap::<usize, (usize, bool)>::new(),
+            mono_quads: HashMap::<usize, (usize, usize, usize, usize)>::new(),
+            status: BTreeMap::<StatusEdge<T>, (usize, ChainOption)>::new()
+        };
+
+        for r in 0..container.rankygon.len()
+		{
+            let current_index = container.rankygon.rank(r).index;
+            container.remove_ending_edges(r);
+
+            if container.chain_followers.contains_key(&current_index)
+                { container.edges_across(r)?; }
+            else if container.chain_mergers.contains_key(&current_index)
+                { container.edges_backward(r)?;}
+            else 
+                { container.edges_forward(r)?; }
+		}
+
+        Ok(container.monos)
+    }
+
+    // All the edges ending at a point leave the status together, before the first of the vertices there is swept
+    fn remove_ending_edges(&mut self, current_rank: usize)
+    {
+        let current_xy = self.rankygon.rank(current_rank).xy;
+        if current_rank > 0 && same_place(self.rankygon.rank(current_rank - 1).xy, current_xy)
+            { return; }
+
+        for rank in (current_rank..self.rankygon.len()).take_while(|rank| same_place(self.rankygon.rank(*rank).xy, current_xy))
+        {
+            let current = self.rankygon.rank(rank);
+            for neighbour in [current.before(), current.after()]
+            {
+                if precedes(neighbour.xy, current.xy)
+                    { self.status.remove(&StatusEdge::new(neighbour.xy, current.xy)); }
+            }
+        }
+    }
+
+    fn edges_forward(&mut self, current_rank: usize) -> Result<(), TriangulationError>
+    {
+        let (current_index, current_xy) = { let current = self.rankygon.rank(current_rank); (current.index, current.xy) };
+        let (top_follower_index, bottom_follower_index) = self.rankygon.forward_followers(current_index);
+
+        // The nearest active edge above the new top edge is the top chain of the mono it's in, if it's in one at all.
+        // Going by the edge rather than the point sorts out other vertices in the same place.
+        let probe = StatusEdge::new(current_xy, self.rankygon.index(top_follower_index).xy);
+        let split_mono_index = match self.status.range(..probe).next_back()
+        {
+            Some((_, &(mono_index, ChainOption::Top))) => Some(mono_index),
+            _ => None,
+        };
+
+        if let Some(split_mono_index) = split_mono_index
+        {
+            if !self.merged_monos.contains_key(&split_mono_index)
+            {
+                // The diagonal goes back to whichever chain was added to most recently, anything else could cross a chain
+                let (top_prev_added, top_follower, bottom_prev_added, bottom_follower) = *self.mono_quads.get(&split_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current_index })?;
+                let top_prev_added_is_newer = 
+                {
+                    let top_prev_added = self.rankygon.index(top_prev_added).xy;
+                    let bottom_prev_added = self.rankygon.index(bottom_prev_added).xy;
+                    top_prev_added.x > bottom_prev_added.x || (top_prev_added.x == bottom_prev_added.x && top_prev_added.y > bottom_prev_added.y)
+                };
+
+                let mono_index = self.monos.len();
+                self.mono_quads.insert(mono_index, (0, 0, 0, 0));
+
+                if top_prev_added_is_newer
+                {
+                    self.monos[split_mono_index].push(current_index, current_xy, ChainOption::Top);
+                    self.setup_followers(current_index, bottom_follower_index, split_mono_index, ChainOption::Top)?;
+
+                    let mut mono = Monotone::new();
+                    mono.push(top_prev_added, self.rankygon.index(top_prev_added).xy, ChainOption::Both);
+                    mono.push(current_index, current_xy, ChainOption::Bottom);
+                    self.monos.push(mono);
+                    self.hand_over_follower(top_prev_added, top_follower, mono_index, ChainOption::Top)?;
+                    self.setup_followers(current_index, top_follower_index, mono_index, ChainOption::Bottom)?;
+                }
+                else
+                {
+                    self.monos[split_mono_index].push(current_index, current_xy, ChainOption::Bottom);
+                    self.setup_followers(current_index, top_follower_index, split_mono_index, ChainOption::Bottom)?;
+
+                    let mut mono = Monotone::new();
+                    mono.push(bottom_prev_added, self.rankygon.index(bottom_prev_added).xy, ChainOption::Both);
+                    mono.push(current_index, current_xy, ChainOption::Top);
+                    self.monos.push(mono);
+                    self.setup_followers(current_index, bottom_follower_index, mono_index, ChainOption::Top)?;
+                    self.hand_over_follower(bottom_prev_added, bottom_follower, mono_index, ChainOption::Bottom)?;
+                }
+            }
+            else
+            {
+                let (other_mono_index, split_mono_top_or_bottom) = self.merged_monos.remove(&split_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current_index })?;
+                self.merged_monos.remove(&other_mono_index);
+
+                let (top_mono_index, bottom_mono_index) = 
+					if split_mono_top_or_bottom
+						{(split_mono_index, other_mono_index)}
+					else 
+						{(other_mono_index, split_mono_index)};
+
+                self.monos[top_mono_index].push(current_index, current_xy, ChainOption::Bottom);
+                self.monos[bottom_mono_index].push(current_index, current_xy, ChainOption::Top);
+                
+                self.setup_followers(current_index, top_follower_index, top_mono_index, ChainOption::Bottom)?;
+                self.setup_followers(current_index, bottom_follower_index, bottom_mono_index, ChainOption::Top)?;
+            }
+        }
+        else
+        {
+            let mut mono = Monotone::new();
+            mono.push(current_index, current_xy, ChainOption::Both);
+            self.monos.push(mono);
+            let mono_index = self.monos.len() - 1;
+
+            self.mono_quads.insert(mono_index, (0, 0, 0, 0));
+            self.setup_followers(current_index, top_follower_index, mono_index, ChainOption::Top)?;
+            self.setup_followers(current_index, bottom_follower_index, mono_index, ChainOption::Bottom)?;
+        }
+
+        Ok(())
+    }
+
+    fn edges_across(&mut self, current_rank: usize) -> Result<(), TriangulationError>
+    {
+        let current = self.rankygon.rank(current_rank);
+        let (mut mono_index, chain_type) = self.chain_followers.remove(&current.index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+
+        if let Some((other_mono_index, _)) = self.merged_monos.remove(&mono_index)
+        {
+            self.merged_monos.remove(&other_mono_index);
+            self.monos[mono_index].push(current.index, current.xy, ChainOption::Both);
+            self.mono_quads.remove(&mono_index);
+            mono_index = other_mono_index
+        };
+
+        let mono = &mut self.monos[mono_index];
+        mono.push(current.index, current.xy, chain_type);
+
+        let follower_index = 
+        {
+            let a = current.before(); let b = current.after();
+            if a.xy.x < b.xy.x { b.index }
+            else if a.xy.x > b.xy.x { a.index }
+            else if a.xy.y < b.xy.y { b.index }
+            else { a.index  }
+        };
+
+        self.setup_followers(current.index, follower_index, mono_index, chain_type)
+    }
+
+    fn edges_backward(&mut self, current_rank: usize) -> Result<(), TriangulationError>
+    {
+        let current = self.rankygon.rank(current_rank);
+        let (mut top_mono_index, mut bottom_mono_index) = self.chain_mergers.remove(&current.index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+
+        if top_mono_index == bottom_mono_index
+		{ 
+            self.monos[top_mono_index].push(current.index, current.xy, ChainOption::Both);
+            self.mono_quads.remove(&top_mono_index);
+        }
+        else if self.merged_monos.get(&top_mono_index).is_some_and(|(other, _)| *other == bottom_mono_index)
+        {
+            self.merged_monos.remove(&top_mono_index);
+            self.merged_monos.remove(&bottom_mono_index);
+            
+            self.monos[top_mono_index].push(current.index, current.xy, ChainOption::Both);
+            self.monos[bottom_mono_index].push(current.index, current.xy, ChainOption::Both);
+            self.mono_quads.remove(&top_mono_index);
+            self.mono_quads.remove(&bottom_mono_index);
+        }
+        else
+        {
+            // A mono left between two merges is finished here, its neighbour takes its place in the new merge
+            for mono_index in [&mut top_mono_index, &mut bottom_mono_index]
+            {
+                if let Some((other_mono_index, _)) = self.merged_monos.remove(mono_index)
+                {
+                    self.merged_monos.remove(&other_mono_index);
+                    self.monos[*mono_index].push(current.index, current.xy, ChainOption::Both);
+                    self.mono_quads.remove(mono_index);
+                    *mono_index = other_mono_index;
+                }
+            }
+
+            self.merged_monos.insert(top_mono_index, (bottom_mono_index, true));
+            self.merged_monos.insert(bottom_mono_index, (top_mono_index, false));
+
+            {
+                let top_quad_copy = *self.mono_quads.get(&top_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+                let bottom_quad = self.mono_quads.get_mut(&bottom_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+                bottom_quad.0 = top_quad_copy.0;
+                bottom_quad.1 = top_quad_copy.1;
+            }
+
+            {
+                let bottom_quad_copy = *self.mono_quads.get(&bottom_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+                let top_quad = self.mono_quads.get_mut(&top_mono_index).ok_or(TriangulationError::InternalInconsistency { index: current.index })?;
+                top_quad.2 = bottom_quad_copy.2;
+                top_quad.3 = bottom_quad_copy.3;
+            }
+
+            self.monos[top_mono_index].push(current.index, current.xy, ChainOption::Bottom);
+			self.monos[bottom_mono_index].push(current.index, current.xy, ChainOption::Top);
+        }
+
+        Ok(())
+    }
+
+    fn setup_followers(&mut self, current_index: usize, follower_index: usize, mono_index: usize, chain_type: ChainOption) -> Result<(), TriangulationError>
+    {
+        self.set_quad(current_index, follower_index, mono_index, chain_type)?;
+
+        if let Some((already_mono_index, _)) = self.chain_followers.remove(&follower_index)
+        { 
+            if chain_type == ChainOption::Top
+                { self.chain_mergers.insert(follower_index, (already_mono_index, mono_index)); }
+            else
+                { self.chain_mergers.insert(follower_index, (mono_index, already_mono_index)); }
+        }
+        else
+		{ 
+            self.chain_followers.insert(follower_index, (mono_index, chain_type));
+        }
+
+        Ok(())
+    }
+
+    fn hand_over_follower(&mut self, current_index: usize, follower_index: usize, mono_index: usize, chain_type: ChainOption) -> Result<(), TriangulationError>
+    {
+        self.set_quad(current_index, follower_index, mono_index, chain_type)?;
+
+        // The follower may already be waiting on a merge, if so the merge has to point at the new mono instead
+        if let Some(merger) = self.chain_mergers.get_mut(&follower_index)
+        {
+            if chain_type == ChainOption::Top
+                { merger.1 = mono_index; }
+            else
+                { merger.0 = mono_index; }
+        }
+        else
+        {
+            self.chain_followers.insert(follower_index, (mono_index, chain_type));
+        }
+
+        Ok(())
+    }
+
+    fn set_quad(&mut self, current_index: usize, follower_index: usize, mono_index: usize, chain_type: ChainOption) -> Result<(), TriangulationError>
+    {
+        let edge = StatusEdge::new(self.rankygon.index(current_index).xy, self.rankygon.index(follower_index).xy);
+        self.status.insert(edge, (mono_index, chain_type));
+
+        let quad = self.mono_quads.get_mut(&mono_index).ok_or(TriangulationError::InternalInconsistency { index: current_index })?;
+		if chain_type == ChainOption::Top
+			{ quad.0 = current_index; quad.1 = follower_index; }
+		else 
+			{ quad.2 = current_index; quad.3 = follower_index; }
+
+        Ok(())
+    }
+}
+
+struct MonotoneToTriangles;
+
+impl MonotoneToTriangles
+{
+    fn convert<T: Scalar>(mono: Monotone<T>) -> Vec<[u32; 3]> 
+    {
+        let points = &mono.points;
+        let mut triangles = Vec::<[u32; 3]>::with_capacity(points.len().saturating_sub(2));
+        if points.len() < 3
+            { return triangles; }
+
+        let mut stack = vec!(&points[0], &points[1]);
+        for c in &points[2..points.len()-1]
+        {
+            let top = *stack.last().unwrap();
+            if c.chain != top.chain
+            {
+                // Everything left on the stack can be seen from c, fan out to all of it
+                for pair in stack.windows(2)
+                    { MonotoneToTriangles::push_triangle(&mut triangles, c, pair[0], pair[1]); }
+                stack = vec!(top, c);
+            }
+            else
+            {
+                let mut b = stack.pop().unwrap();
+                while let Some(&a) = stack.last()
+                {
+                    // A point the chain passes through twice hides nothing, it's what's behind it that matters
+                    let side_of_line = MonotoneToTriangles::side_of_line(a, b, c);
+                    let is_repeat = same_place(a.xy, b.xy);
+                    if !is_repeat && ((c.chain == ChainOption::Top && side_of_line <= 0.0) || (c.chain == ChainOption::Bottom && side_of_line >= 0.0))
+                        { break; }
+
+                    MonotoneToTriangles::push_triangle(&mut triangles, a, b, c);
+                    b = stack.pop().unwrap();
+                }
+                stack.push(b);
+                stack.push(c);
+            }
+        }
+
+        let last = &points[points.len()-1];
+        for pair in stack.windows(2)
+            { MonotoneToTriangles::push_triangle(&mut triangles, last, pair[0], pair[1]); }
+
+        triangles
+    }
+
+    fn side_of_line<T: Scalar>(a: &MonoPoint<T>, b: &MonoPoint<T>, c: &MonoPoint<T>) -> f64
+    {
+        T::orient2d(a.xy, b.xy, c.xy)
+    }
+
+    fn push_triangle<T: Scalar>(triangles: &mut Vec<[u32; 3]>, a: &MonoPoint<T>, b: &MonoPoint<T>, c: &MonoPoint<T>)
+    {
+        // Two copies of a point where the polygon touches itself only make a triangle with no area
+        if same_place(a.xy, b.xy) || same_place(b.xy, c.xy) || same_place(c.xy, a.xy)
+            { return; }
+
+        if MonotoneToTriangles::side_of_line(a, b, c) >= 0.0
+            { triangles.push([a.index as u32, b.index as u32, c.index as u32]); }
+        else
+            { triangles.push([a.index as u32, c.index as u32, b.index as u32]); }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriangulationError
+{
+    TooFewPoints { count: usize },
+    DuplicateVertex { index: usize, first_index: usize },
+    SelfIntersection { index: usize, other_index: usize }, // The edges starting at each index cross
+    NonFiniteCoordinate { index: usize },
+    InternalInconsistency { index: usize },
+    NoSuchVertex { index: usize }, // An edit named a vertex that isn't on the ring
+}
+
+impl std::fmt::Display for TriangulationError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            TriangulationError::TooFewPoints { count } => write!(f, "polygon needs at least 3 points, got {}", count),
+            TriangulationError::DuplicateVertex { index, first_index } => write!(f, "vertex {} is a duplicate of vertex {}", index, first_index),
+            TriangulationError::SelfIntersection { index, other_index } => write!(f, "edge from vertex {} crosses edge from vertex {}", index, other_index),
+            TriangulationError::NonFiniteCoordinate { index } => write!(f, "vertex {} has a non-finite coordinate", index),
+            TriangulationError::InternalInconsistency { index } => write!(f, "sweep lost track of the polygon at vertex {}", index),
+            TriangulationError::NoSuchVertex { index } => write!(f, "vertex {} isn't on the ring", index),
+        }
+    }
+}
+
+impl std::error::Error for TriangulationError {}
+
+pub type Triangle<T = f32> = (XY<T>, XY<T>, XY<T>);
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Winding { Clockwise, CounterClockwise }
+
+// Twice the area is summed up in f64, long thin paths lose too much of it in f32
+pub fn signed_area<T: Scalar>(points: &[XY<T>]) -> f64
+{
+    let double_area: f64 = (0..points.len()).map(|i|
+    {
+        let a = points[i]; let b = points[(i + 1) % points.len()];
+        a.x.to_f64() * b.y.to_f64() - b.x.to_f64() * a.y.to_f64()
+    }).sum();
+    double_area / 2.0
+}
+
+pub fn winding<T: Scalar>(points: &[XY<T>]) -> Winding
+{
+    if signed_area(points) < 0.0 { Winding::Clockwise } else { Winding::CounterClockwise }
+}
+
+// Triangles come out of the sweep counter-clockwise whichever way round the input went
+pub fn set_winding(triangles: &mut [[u32; 3]], winding: Winding)
+{
+    if winding == Winding::Clockwise
+    {
+        for triangle in triangles
+            { triangle.swap(1, 2); }
+    }
+}
+
+pub fn calc_with_winding<T: Scalar>(points: &[XY<T>], winding: Winding) -> Vec<Triangle<T>>
+{
+    let mut triangles = calc_indexed(points);
+    set_winding(&mut triangles, winding);
+    to_triangles(points, &triangles)
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum FillRule { EvenOdd, NonZero, Positive, Negative }
+
+impl FillRule
+{
+    pub fn fills(&self, winding_number: i32) -> bool
+    {
+        match self
+        {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+            FillRule::Positive => winding_number > 0,
+            FillRule::Negative => winding_number < 0,
+        }
+    }
+}
+
+pub fn calc<T: Scalar>(points: &[XY<T>]) -> Vec<Triangle<T>>
+{
+    calc_with_holes(points, &[])
+}
+
+pub fn calc_indexed<T: Scalar>(points: &[XY<T>]) -> Vec<[u32; 3]>
+{
+    calc_with_holes_indexed(points, &[])
+}
+
+// Nothing is checked on the way in, so a ring that crosses itself can lose the sweep and come back with no
+// triangles at all. The try_ versions say what was wrong instead.
+pub fn calc_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Vec<Triangle<T>>
+{
+    let rings = with_holes(outer, holes);
+    to_triangles(&rings.concat(), &triangulate(Rankygon::new(&rings)).unwrap_or_default())
+}
+
+// The indices run through the outer ring and then each hole in turn, as if they were all one list
+pub fn calc_with_holes_indexed<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Vec<[u32; 3]>
+{
+    triangulate(Rankygon::new(&with_holes(outer, holes))).unwrap_or_default()
+}
+
+pub fn try_calc_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Result<Vec<Triangle<T>>, TriangulationError>
+{
+    let rings = with_holes(outer, holes);
+    let triangles = triangulate(checked_rankygon(&rings)?)?;
+    Ok(to_triangles(&rings.concat(), &triangles))
+}
+
+pub fn try_calc_with_holes_indexed<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Result<Vec<[u32; 3]>, TriangulationError>
+{
+    triangulate(checked_rankygon(&with_holes(outer, holes))?)
+}
+
+fn with_holes<'a, T: Scalar>(outer: &'a [XY<T>], holes: &'a [Vec<XY<T>>]) -> Vec<&'a [XY<T>]>
+{
+    let mut rings = vec!(outer);
+    rings.extend(holes.iter().map(|hole| hole.as_slice()));
+    rings
+}
+
+// Every ring has to be simple on its own and keep clear of the others, the indices in the errors run through the
+// rings in turn the same as the triangles' do
+fn checked_rankygon<T: Scalar>(rings: &[&[XY<T>]]) -> Result<Rankygon<T>, TriangulationError>
+{
+    if let Some(ring) = rings.iter().find(|ring| ring.len() < 3)
+        { return Err(TriangulationError::TooFewPoints { count: ring.len() }); }
+
+    if let Some(index) = rings.iter().copied().flatten().position(|xy| !xy.x.is_finite() || !xy.y.is_finite())
+        { return Err(TriangulationError::NonFiniteCoordinate { index }); }
+
+    // Rankygon::new drops a point repeated straight after itself, so those have to be caught before it does
+    let mut start = 0;
+    for ring in rings
+    {
+        if let Some(i) = (0..ring.len()).find(|&i| same_place(ring[i], ring[(i + 1) % ring.len()]))
+        {
+            let (first_index, index) = if i + 1 == ring.len() { (0, i) } else { (i, i + 1) };
+            return Err(TriangulationError::DuplicateVertex { index: start + index, first_index: start + first_index });
+        }
+        start += ring.len();
+    }
+
+    let rankygon = Rankygon::new(rings);
+    for r in 1..rankygon.len()
+    {
+        let (a, b) = (rankygon.rank(r - 1), rankygon.rank(r));
+        if a.xy.x == b.xy.x && a.xy.y == b.xy.y
+            { return Err(TriangulationError::DuplicateVertex { index: a.index.max(b.index), first_index: a.index.min(b.index) }); }
+    }
+
+    if let Some(intersection) = intersections::find_ring_intersections(rings).first()
+        { return Err(TriangulationError::SelfIntersection { index: intersection.edge_a, other_index: intersection.edge_b }); }
+
+    Ok(rankygon)
+}
+
+pub fn calc_untangled(points: &[XY]) -> Vec<Triangle>
+{
+    calc_filled(&[points.to_vec()], FillRule::NonZero)
+}
+
+// The sweep can't tell apart points in the same place, so where rings touch each copy of the point is moved a
+// tiny way into its own corner for the sweep. The triangles still use the points where they really are.
+pub(crate) fn pull_apart(rings: &[Vec<XY>]) -> Vec<Vec<XY>>
+{
+    let key = |xy: &XY| ((xy.x + 0.0).to_bits(), (xy.y + 0.0).to_bits());
+    let scale = rings.iter().flatten().map(|xy| xy.x.abs().max(xy.y.abs())).fold(1.0, f32::max);
+
+    // How many rings pass through each point, and how short the edges there get, to stay well short of them
+    let mut copies = HashMap::<(u32, u32), (usize, f32)>::new();
+    for ring in rings
+    {
+        for i in 0..ring.len()
+        {
+            let (at, after) = (ring[i], ring[(i + 1) % ring.len()]);
+            let length = (after.x - at.x).hypot(after.y - at.y);
+            for (end, count) in [(at, 1), (after, 0)]
+            {
+                let entry = copies.entry(key(&end)).or_insert((0, scale * 1e-5));
+                entry.0 += count;
+                entry.1 = entry.1.min(length / 10.0);
+            }
+        }
+    }
+
+    let mut pulled = rings.to_vec();
+    for (ring, pulled) in rings.iter().zip(pulled.iter_mut())
+    {
+        for i in 0..ring.len()
+        {
+            let at = ring[i];
+            let (count, distance) = copies[&key(&at)];
+            if count < 2
+                { continue; }
+
+            let before = ring[(i + ring.len() - 1) % ring.len()];
+            let after = ring[(i + 1) % ring.len()];
+            let back = (before.y - at.y).atan2(before.x - at.x);
+            let out = (after.y - at.y).atan2(after.x - at.x);
+            let middle = out + (back - out).rem_euclid(std::f32::consts::TAU) / 2.0;
+            pulled[i] = XY::new(at.x + middle.cos() * distance, at.y + middle.sin() * distance);
+        }
+    }
+
+    pulled
+}
+
+// Contours can overlap, nest and cross each other and themselves, only what the fill rule fills gets triangulated
+pub fn calc_filled(contours: &[Vec<XY>], fill_rule: FillRule) -> Vec<Triangle>
+{
+    try_calc_filled(contours, fill_rule).unwrap_or_default()
+}
+
+// Any contours at all can be untangled, so only a non-finite coordinate or the sweep losing its way is an error
+pub fn try_calc_filled(contours: &[Vec<XY>], fill_rule: FillRule) -> Result<Vec<Triangle>, TriangulationError>
+{
+    if let Some(index) = contours.iter().flatten().position(|xy| !xy.x.is_finite() || !xy.y.is_finite())
+        { return Err(TriangulationError::NonFiniteCoordinate { index }); }
+
+    let contours: Vec<&[XY]> = contours.iter().map(|contour| contour.as_slice()).collect();
+    let rings = intersections::untangle_rings(&contours, fill_rule);
+    let pulled = pull_apart(&rings);
+    let pulled: Vec<&[XY]> = pulled.iter().map(|ring| ring.as_slice()).collect();
+    let points = rings.concat();
+    Ok(to_triangles(&points, &triangulate(Rankygon::new(&pulled))?))
+}
+
+pub fn try_calc<T: Scalar>(points: &[XY<T>]) -> Result<Vec<Triangle<T>>, TriangulationError>
+{
+    let triangles = try_calc_indexed(points)?;
+    Ok(to_triangles(points, &triangles))
+}
+
+pub fn try_calc_indexed<T: Scalar>(points: &[XY<T>]) -> Result<Vec<[u32; 3]>, TriangulationError>
+{
+    triangulate(checked_rankygon(&[points])?)
+}
+
+pub fn to_triangles<T: Copy>(points: &[XY<T>], indices: &[[u32; 3]]) -> Vec<Triangle<T>>
+{
+    indices.iter().map(|[a, b, c]| (points[*a as usize], points[*b as usize], points[*c as usize])).collect()
+}
+
+// A piece of the polygon that only goes left to right along its two chains, before it's cut into triangles
+#[derive(Debug, Clone)]
+pub struct MonotonePiece
+{
+    pub vertices: Vec<(u32 /*Index*/, ChainOption)>, // In sweep order, the first and last are on both chains and the top chain has the smaller y
+}
+
+pub fn calc_monotones<T: Scalar>(points: &[XY<T>]) -> Vec<MonotonePiece>
+{
+    calc_monotones_with_holes(points, &[])
+}
+
+// Indexed the same way as calc_with_holes_indexed
+pub fn calc_monotones_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Vec<MonotonePiece>
+{
+    to_pieces(RankygonToMonos::convert(Rankygon::new(&with_holes(outer, holes))).unwrap_or_default())
+}
+
+pub fn try_calc_monotones_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>]) -> Result<Vec<MonotonePiece>, TriangulationError>
+{
+    Ok(to_pieces(RankygonToMonos::convert(checked_rankygon(&with_holes(outer, holes))?)?))
+}
+
+fn to_pieces<T: Scalar>(monos: Vec<Monotone<T>>) -> Vec<MonotonePiece>
+{
+    monos.iter()
+        .map(|mono| MonotonePiece { vertices: mono.points.iter().map(|point| (point.index as u32, point.chain)).collect() })
+        .collect()
+}
+
+pub fn calc_strips<T: Scalar>(points: &[XY<T>], restart_index: Option<u32>) -> Vec<u32>
+{
+    calc_strips_with_holes(points, &[], restart_index)
+}
+
+// The triangles of each monotone are put in strips on their own, they come out of it as fans and zigzags that
+// strips suit well
+pub fn calc_strips_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>], restart_index: Option<u32>) -> Vec<u32>
+{
+    to_strips(RankygonToMonos::convert(Rankygon::new(&with_holes(outer, holes))).unwrap_or_default(), restart_index)
+}
+
+pub fn try_calc_strips_with_holes<T: Scalar>(outer: &[XY<T>], holes: &[Vec<XY<T>>], restart_index: Option<u32>) -> Result<Vec<u32>, TriangulationError>
+{
+    Ok(to_strips(RankygonToMonos::convert(checked_rankygon(&with_holes(outer, holes))?)?, restart_index))
+}
+
+fn to_strips<T: Scalar>(monos: Vec<Monotone<T>>, restart_index: Option<u32>) -> Vec<u32>
+{
+    let strips: Vec<Vec<u32>> = monos.into_iter()
+        .map(|mono| strips::from_triangles(&MonotoneToTriangles::convert(mono), restart_index))
+        .collect();
+    strips::join(&strips, restart_index)
+}
+
+fn triangulate<T: Scalar>(rankygon: Rankygon<T>) -> Result<Vec<[u32; 3]>, TriangulationError>
+{
+    let monos = RankygonToMonos::convert(rankygon)?;
+    let mut triangles = Vec::<[u32; 3]>::new();
+    for mono in monos
+        { triangles.append(&mut MonotoneToTriangles::convert(mono)); }
+    Ok(triangles)
+}
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::validation;
+
+    fn ring(points: &[(i32, i32)]) -> Vec<XY>
+    {
+        points.iter().map(|&(x, y)| XY::new(x as f32, y as f32)).collect()
+    }
+
+    fn assert_triangulates(points: &[XY])
+    {
+        let triangles = try_calc(points).unwrap();
+        let report = validation::verify(points, &triangles);
+        assert!(report.is_valid(), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn vertical_edges()
+    {
+        assert_triangulates(&ring(&[(0, 0), (4, 0), (4, 1), (1, 1), (1, 3), (4, 3), (4, 4), (0, 4)]));
+        assert_triangulates(&ring(&[(0, 0), (1, 0), (1, 2), (2, 2), (2, 0), (3, 0), (3, 3), (0, 3)]));
+    }
+
+    #[test]
+    fn collinear_points()
+    {
+        assert_triangulates(&ring(&[(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (2, 2), (1, 2), (0, 2), (0, 1)]));
+        assert_triangulates(&ring(&[(0, 0), (1, 1), (2, 2), (3, 3), (0, 3)]));
+    }
+
+    #[test]
+    fn duplicate_vertices()
+    {
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (0, 0), (1, 0), (1, 1), (0, 1)])),
+            Err(TriangulationError::DuplicateVertex { index: 1, first_index: 0 }));
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (1, 0), (1, 1), (0, 1), (0, 0)])),
+            Err(TriangulationError::DuplicateVertex { index: 4, first_index: 0 }));
+        assert_eq!(try_calc_with_holes_indexed(&ring(&[(0, 0), (4, 0), (4, 4), (0, 4)]), &[ring(&[(1, 1), (1, 2), (1, 2), (2, 1)])]),
+            Err(TriangulationError::DuplicateVertex { index: 6, first_index: 5 }));
+    }
+
+    #[test]
+    fn spikes()
+    {
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (4, 0), (4, 4), (2, 4), (2, 6), (2, 5), (0, 4)])),
+            Err(TriangulationError::SelfIntersection { index: 3, other_index: 5 }));
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (4, 0), (4, 4), (2, 4), (2, 6), (2, 4), (0, 4)])),
+            Err(TriangulationError::SelfIntersection { index: 2, other_index: 5 }));
+    }
+
+    #[test]
+    fn touching_vertices()
+    {
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 1)])),
+            Err(TriangulationError::DuplicateVertex { index: 5, first_index: 2 }));
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (4, 0), (4, 4), (2, 0), (0, 4)])),
+            Err(TriangulationError::SelfIntersection { index: 0, other_index: 3 }));
+    }
+
+    #[test]
+    fn other_errors()
+    {
+        assert_eq!(try_calc_indexed(&ring(&[(0, 0), (1, 0)])), Err(TriangulationError::TooFewPoints { count: 2 }));
+        assert_eq!(try_calc_indexed(&[XY::new(0.0, 0.0), XY::new(1.0, f32::NAN), XY::new(0.0, 1.0)]),
+            Err(TriangulationError::NonFiniteCoordinate { index: 1 }));
+        assert_eq!(try_calc_with_holes_indexed(&ring(&[(0, 0), (4, 0), (4, 4), (0, 4)]), &[ring(&[(1, 1), (5, 2), (1, 2)])]),
+            Err(TriangulationError::SelfIntersection { index: 1, other_index: 4 }));
+    }
+}
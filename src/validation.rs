@@ -0,0 +1,366 @@
+use crate::XY;
+use crate::intersections;
+use crate::scalar::Scalar;
+use crate::triangulator::{folds_back, same_place, Triangle};
+
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Issue<T = f32>
+{
+    TooFewPoints { count: usize }, // Not counting points repeated straight after themselves
+    NonFiniteCoordinate { index: usize },
+    ZeroLengthEdge { index: usize, xy: XY<T> }, // The edge from index to the point after it
+    DuplicateVertex { index: usize, first_index: usize, xy: XY<T> },
+    Spike { index: usize, xy: XY<T> }, // The path turns straight back the way it came at index
+    SelfIntersection { edge_a: usize, edge_b: usize, xy: XY<f64> }, // Edges can cross between the points a T can hold
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Issue<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Issue::TooFewPoints { count } => write!(f, "polygon needs at least 3 distinct points, got {}", count),
+            Issue::NonFiniteCoordinate { index } => write!(f, "vertex {} has a non-finite coordinate", index),
+            Issue::ZeroLengthEdge { index, xy } => write!(f, "edge from vertex {} has zero length at ({}, {})", index, xy.x, xy.y),
+            Issue::DuplicateVertex { index, first_index, xy } => write!(f, "vertex {} is a duplicate of vertex {} at ({}, {})", index, first_index, xy.x, xy.y),
+            Issue::Spike { index, xy } => write!(f, "path turns straight back at vertex {} at ({}, {})", index, xy.x, xy.y),
+            Issue::SelfIntersection { edge_a, edge_b, xy } => write!(f, "edge from vertex {} crosses edge from vertex {} at ({}, {})", edge_a, edge_b, xy.x, xy.y),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidationReport<T = f32>
+{
+    pub issues: Vec<Issue<T>>,
+}
+
+impl<T> ValidationReport<T>
+{
+    pub fn is_simple(&self) -> bool { self.issues.is_empty() }
+}
+
+// Everything wrong with the ring that would stop it being simple, rather than just the first thing found
+pub fn validate<T: Scalar>(points: &[XY<T>]) -> ValidationReport<T>
+{
+    let mut issues = vec!();
+
+    let non_finite: Vec<usize> = (0..points.len()).filter(|&i| !points[i].x.is_finite() || !points[i].y.is_finite()).collect();
+    issues.extend(non_finite.iter().map(|&index| Issue::NonFiniteCoordinate { index }));
+
+    // Nothing can be said about where the edges go without all the points
+    if !non_finite.is_empty()
+        { return ValidationReport { issues }; }
+
+    // A point repeated straight after itself makes an edge with no length, the rest of the checks skip the repeats
+    let mut kept = Vec::<usize /*Point Index*/>::with_capacity(points.len());
+    for i in 0..points.len()
+    {
+        let next = (i + 1) % points.len();
+        if next != i && same_place(points[i], points[next])
+            { issues.push(Issue::ZeroLengthEdge { index: i, xy: points[i] }); }
+        else
+            { kept.push(i); }
+    }
+
+    if kept.len() < 3
+    {
+        // A ring that never leaves its first point still has that one
+        let count = if kept.is_empty() { points.len().min(1) } else { kept.len() };
+        issues.push(Issue::TooFewPoints { count });
+        return ValidationReport { issues };
+    }
+
+    let mut by_place = kept.clone();
+    by_place.sort_by(|&a, &b| (points[a].x, points[a].y).partial_cmp(&(points[b].x, points[b].y)).unwrap().then(a.cmp(&b)));
+    let mut first_index = by_place[0];
+    for pair in by_place.windows(2)
+    {
+        if same_place(points[pair[0]], points[pair[1]])
+            { issues.push(Issue::DuplicateVertex { index: pair[1], first_index, xy: points[pair[1]] }); }
+        else
+            { first_index = pair[1]; }
+    }
+
+    for k in 0..kept.len()
+    {
+        let (before, at, after) = (kept[(k + kept.len() - 1) % kept.len()], kept[k], kept[(k + 1) % kept.len()]);
+        if folds_back(points[before], points[at], points[after])
+            { issues.push(Issue::Spike { index: at, xy: points[at] }); }
+    }
+
+    let ring: Vec<XY<T>> = kept.iter().map(|&i| points[i]).collect();
+    for intersection in intersections::find_intersections(&ring)
+    {
+        let (edge_a, edge_b) = (kept[intersection.edge_a], kept[intersection.edge_b]);
+        let ends = |k: usize| [ring[k], ring[(k + 1) % ring.len()]];
+
+        // Edges meeting end to end at a repeated point are already down as a duplicate vertex
+        let at_shared_end = ends(intersection.edge_a).iter()
+            .any(|&a| ends(intersection.edge_b).iter().any(|&b| same_place(a, b) && a.x.to_f64() == intersection.xy.x && a.y.to_f64() == intersection.xy.y));
+        if !at_shared_end
+            { issues.push(Issue::SelfIntersection { edge_a, edge_b, xy: intersection.xy }); }
+    }
+
+    ValidationReport { issues }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Problem
+{
+    TriangleCount { expected: usize, count: usize }, // Only checked when the polygon is simple
+    AreaMismatch { polygon_area: f64, triangle_area: f64 },
+    Clockwise { triangle: usize },
+    BoundaryEdge { index: usize, count: usize }, // How many triangles the edge from index to the point after it is in
+    Overlap { triangle_a: usize, triangle_b: usize },
+    Outside { triangle: usize },
+}
+
+impl std::fmt::Display for Problem
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Problem::TriangleCount { expected, count } => write!(f, "expected {} triangles, got {}", expected, count),
+            Problem::AreaMismatch { polygon_area, triangle_area } => write!(f, "triangles add up to an area of {} but the polygon's is {}", triangle_area, polygon_area),
+            Problem::Clockwise { triangle } => write!(f, "triangle {} goes clockwise", triangle),
+            Problem::BoundaryEdge { index, count } => write!(f, "edge from vertex {} is in {} triangles", index, count),
+            Problem::Overlap { triangle_a, triangle_b } => write!(f, "triangles {} and {} overlap", triangle_a, triangle_b),
+            Problem::Outside { triangle } => write!(f, "triangle {} is at least partly outside the polygon", triangle),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyReport
+{
+    pub problems: Vec<Problem>,
+}
+
+impl VerifyReport
+{
+    pub fn is_valid(&self) -> bool { self.problems.is_empty() }
+}
+
+fn twice_area<T: Scalar>(a: XY<T>, b: XY<T>, c: XY<T>) -> f64
+{
+    (b.x.to_f64() - a.x.to_f64()) * (c.y.to_f64() - a.y.to_f64()) - (b.y.to_f64() - a.y.to_f64()) * (c.x.to_f64() - a.x.to_f64())
+}
+
+// Checks triangles from calc against the polygon they came from. The edges of counter-clockwise triangles that fill
+// the polygon exactly cancel out in pairs, leaving just the polygon's edges, and when they do nothing can overlap or
+// be outside. Only when they don't are the triangles checked against each other and the polygon, which is slower,
+// to find which of them are to blame.
+pub fn verify<T: Scalar>(polygon: &[XY<T>], triangles: &[Triangle<T>]) -> VerifyReport
+{
+    let mut problems = vec!();
+    let key = |xy: XY<T>| ((xy.x.to_f64() + 0.0).to_bits(), (xy.y.to_f64() + 0.0).to_bits());
+    let n = polygon.len();
+
+    if n >= 3 && validate(polygon).is_simple() && triangles.len() != n - 2
+        { problems.push(Problem::TriangleCount { expected: n - 2, count: triangles.len() }); }
+
+    let polygon_area = (0..n).map(|i| twice_area(polygon[0], polygon[i], polygon[(i + 1) % n])).sum::<f64>() / 2.0;
+    let triangle_area = triangles.iter().map(|&(a, b, c)| twice_area(a, b, c)).sum::<f64>() / 2.0;
+    let scale = polygon_area.abs() + triangles.iter().map(|&(a, b, c)| twice_area(a, b, c).abs()).sum::<f64>() / 2.0;
+    if (triangle_area - polygon_area.abs()).abs() > scale * 1e-9
+        { problems.push(Problem::AreaMismatch { polygon_area: polygon_area.abs(), triangle_area }); }
+
+    let is_clockwise = |&(a, b, c): &Triangle<T>| T::orient2d(a, b, c) < 0.0;
+    problems.extend((0..triangles.len()).filter(|&t| is_clockwise(&triangles[t])).map(|triangle| Problem::Clockwise { triangle }));
+
+    let mut edges = HashMap::<((u64, u64), (u64, u64)) /*Directed Edge*/, usize /*Count*/>::new();
+    for &(a, b, c) in triangles
+    {
+        for (from, to) in [(a, b), (b, c), (c, a)]
+            { *edges.entry((key(from), key(to))).or_default() += 1; }
+    }
+
+    // Going the way the triangles do, the polygon's edges are each in one triangle and every other edge is in two,
+    // once each way
+    let mut boundary = HashMap::<((u64, u64), (u64, u64)), usize>::new();
+    for i in 0..n
+    {
+        let (from, to) = (key(polygon[i]), key(polygon[(i + 1) % n]));
+        if from == to
+            { continue; }
+
+        let count = edges.get(&(from, to)).copied().unwrap_or(0) + edges.get(&(to, from)).copied().unwrap_or(0);
+        if count != 1
+            { problems.push(Problem::BoundaryEdge { index: i, count }); }
+        *boundary.entry(if polygon_area < 0.0 { (to, from) } else { (from, to) }).or_default() += 1;
+    }
+    let cancels = edges.iter().all(|(&(from, to), &count)| match boundary.get(&(from, to))
+    {
+        Some(&times) => count == times && !edges.contains_key(&(to, from)),
+        None => count == 1 && edges.get(&(to, from)) == Some(&1),
+    });
+
+    if !cancels || !problems.is_empty()
+        { find_overlaps_and_outside(polygon, triangles, &mut problems); }
+
+    VerifyReport { problems }
+}
+
+// Whether some edge of the counter-clockwise triangle has all the points on it or to the right of it
+fn separates<T: Scalar>(triangle: &[XY<T>; 3], points: &[XY<T>]) -> bool
+{
+    (0..3).any(|k| points.iter().all(|&p| T::orient2d(triangle[k], triangle[(k + 1) % 3], p) <= 0.0))
+}
+
+// Sweeps left to right over the boxes round the triangles and the polygon's edges, only things in boxes that meet
+// are checked against each other. A triangle is outside if one of the polygon's edges goes through it, or if not,
+// if the middle of it is.
+fn find_overlaps_and_outside<T: Scalar>(polygon: &[XY<T>], triangles: &[Triangle<T>], problems: &mut Vec<Problem>)
+{
+    let n = polygon.len();
+
+    // Triangles with no area can't overlap anything or be outside
+    let corners: Vec<Option<[XY<T>; 3]>> = triangles.iter().map(|&(a, b, c)|
+    {
+        let side = T::orient2d(a, b, c);
+        if side > 0.0 { Some([a, b, c]) } else if side < 0.0 { Some([a, c, b]) } else { None }
+    }).collect();
+
+    let bounds = |points: &[XY<T>]|
+    {
+        let xs = points.iter().map(|p| p.x.to_f64());
+        let ys = points.iter().map(|p| p.y.to_f64());
+        (xs.clone().fold(f64::INFINITY, f64::min), xs.fold(f64::NEG_INFINITY, f64::max), ys.clone().fold(f64::INFINITY, f64::min), ys.fold(f64::NEG_INFINITY, f64::max))
+    };
+    let mut items = Vec::<((f64, f64, f64, f64), Result<usize /*Triangle Index*/, usize /*Edge Index*/>)>::new();
+    items.extend((0..triangles.len()).filter_map(|t| corners[t].map(|corners| (bounds(&corners), Ok(t)))));
+    items.extend((0..n).map(|i| (bounds(&[polygon[i], polygon[(i + 1) % n]]), Err(i))));
+    items.sort_by(|a, b| a.0.0.total_cmp(&b.0.0));
+
+    let mut outside = vec!(false; triangles.len());
+    let mut active = Vec::<usize /*Item Index*/>::new();
+    for i in 0..items.len()
+    {
+        let ((min_x, _, min_y, max_y), item) = items[i];
+        active.retain(|&j| items[j].0.1 >= min_x);
+        for &j in &active
+        {
+            let ((_, _, other_min_y, other_max_y), other) = items[j];
+            if other_max_y < min_y || max_y < other_min_y
+                { continue; }
+
+            match (item, other)
+            {
+                (Ok(a), Ok(b)) =>
+                {
+                    let (first, second) = (corners[a].unwrap(), corners[b].unwrap());
+                    if !separates(&first, &second) && !separates(&second, &first)
+                        { problems.push(Problem::Overlap { triangle_a: a.min(b), triangle_b: a.max(b) }); }
+                },
+                (Ok(t), Err(e)) | (Err(e), Ok(t)) =>
+                {
+                    let (triangle, edge) = (corners[t].unwrap(), [polygon[e], polygon[(e + 1) % n]]);
+                    let sides: Vec<f64> = triangle.iter().map(|&p| T::orient2d(edge[0], edge[1], p)).collect();
+                    let edge_separates = sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0);
+                    if !separates(&triangle, &edge) && !edge_separates
+                        { outside[t] = true; }
+                },
+                (Err(_), Err(_)) => (),
+            }
+        }
+        active.push(i);
+    }
+
+    // The polygon's edges are put in rows by height, so only those in the same row as a point can cross a ray from it
+    let (_, _, bottom, top) = bounds(polygon);
+    let row_count = ((n as f64).sqrt().ceil() as usize).max(1);
+    let row_height = (top - bottom) / row_count as f64;
+    let row = |y: f64| if row_height > 0.0 { (((y - bottom) / row_height) as usize).min(row_count - 1) } else { 0 };
+    let mut rows = vec!(Vec::<usize /*Edge Index*/>::new(); row_count);
+    for i in 0..n
+    {
+        let (a, b) = (polygon[i].y.to_f64(), polygon[(i + 1) % n].y.to_f64());
+        for edges in &mut rows[row(a.min(b))..=row(a.max(b))]
+            { edges.push(i); }
+    }
+
+    for t in 0..triangles.len()
+    {
+        let Some(corners) = corners[t]
+            else { continue; };
+        let (x, y) = (corners.iter().map(|p| p.x.to_f64()).sum::<f64>() / 3.0, corners.iter().map(|p| p.y.to_f64()).sum::<f64>() / 3.0);
+        let winding: i32 = rows[row(y)].iter().map(|&i|
+        {
+            let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+            let (ax, ay, bx, by) = (a.x.to_f64(), a.y.to_f64(), b.x.to_f64(), b.y.to_f64());
+            if (ay <= y) == (by <= y) || ax + (y - ay) / (by - ay) * (bx - ax) <= x { 0 } else if by > ay { 1 } else { -1 }
+        }).sum();
+
+        if outside[t] || winding == 0
+            { problems.push(Problem::Outside { triangle: t }); }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::triangulator;
+
+    // An L with a notch cut in the bottom, so some of the triangles have to reach round corners
+    fn notched() -> Vec<XY>
+    {
+        [(0.0, 0.0), (1.0, 0.0), (1.0, 0.5), (1.5, 0.5), (1.5, 0.0), (3.0, 0.0), (3.0, 1.0), (1.0, 1.0), (1.0, 3.0), (0.0, 3.0)]
+            .iter().map(|&(x, y)| XY::new(x, y)).collect()
+    }
+
+    #[test]
+    fn triangles_from_calc_pass()
+    {
+        let polygon = notched();
+        let report = verify(&polygon, &triangulator::calc(&polygon));
+        assert!(report.is_valid(), "{:?}", report.problems);
+
+        let mut clockwise = polygon.clone();
+        clockwise.reverse();
+        let report = verify(&clockwise, &triangulator::calc(&clockwise));
+        assert!(report.is_valid(), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn missing_triangles_are_caught()
+    {
+        let polygon = notched();
+        let mut triangles = triangulator::calc(&polygon);
+        triangles.remove(3);
+
+        let report = verify(&polygon, &triangles);
+        assert!(report.problems.iter().any(|problem| matches!(problem, Problem::TriangleCount { expected: 8, count: 7 })));
+        assert!(report.problems.iter().any(|problem| matches!(problem, Problem::AreaMismatch { .. })));
+        assert!(!report.problems.iter().any(|problem| matches!(problem, Problem::Overlap { .. } | Problem::Outside { .. })));
+    }
+
+    #[test]
+    fn overlapping_triangles_are_caught()
+    {
+        // Half the square cut off along one diagonal and half along the other, the right count and every edge but the
+        // right quarter is covered twice and the left one not at all
+        let square = vec!(XY::new(0.0, 0.0), XY::new(1.0, 0.0), XY::new(1.0, 1.0), XY::new(0.0, 1.0));
+        let triangles = vec!((square[0], square[1], square[2]), (square[1], square[2], square[3]));
+
+        let report = verify(&square, &triangles);
+        assert!(report.problems.iter().any(|problem| matches!(problem, Problem::Overlap { triangle_a: 0, triangle_b: 1 })), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn triangles_outside_are_caught()
+    {
+        // A triangle filling in half the notch, where the polygon isn't
+        let polygon = notched();
+        let mut triangles = triangulator::calc(&polygon);
+        triangles.push((polygon[1], polygon[4], polygon[3]));
+
+        let report = verify(&polygon, &triangles);
+        assert!(report.problems.iter().any(|problem| matches!(problem, Problem::Outside { triangle: 8 })), "{:?}", report.problems);
+    }
+}